@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use serde::Deserialize;
+use utils::solution::Part;
+
+/// The expected answers for one input file. A part may be left out if it has no known answer
+/// (e.g. day 25 has no part 2) or is too slow to check.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>
+}
+
+/// An input file to check, and the answers expected from it.
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub day: u8,
+    pub input: String,
+    pub answers: Vec<(Part, String)>
+}
+
+/// The contents of `answers.toml`: known answers keyed by day (`[day16."input.txt"]`) and input file.
+pub struct Registry {
+    entries: Vec<Entry>
+}
+
+impl Registry {
+    pub fn load(path: &Path) -> Result<Registry, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!["Couldn't read \"{}\": {e}", path.display()])?;
+        Self::parse(&text).map_err(|e| format!["Couldn't parse \"{}\": {e}", path.display()])
+    }
+
+    fn parse(text: &str) -> Result<Registry, String> {
+        let days: BTreeMap<String, BTreeMap<String, Expected>> = toml::from_str(text).map_err(|e| e.to_string())?;
+
+        let mut entries = vec![];
+        for (key, inputs) in days {
+            let day = key.strip_prefix("day").and_then(|d| d.parse().ok()).ok_or(format!["Invalid day \"{key}\""])?;
+            for (input, expected) in inputs {
+                let answers = [(Part::One, expected.part1), (Part::Two, expected.part2)]
+                    .into_iter()
+                    .filter_map(|(part, answer)| Some((part, answer?)))
+                    .collect();
+                entries.push(Entry { day, input, answers });
+            }
+        }
+        Ok(Registry { entries })
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_entries() {
+        let registry = Registry::parse(r#"
+            [day02."sample.txt"]
+            part1 = "15"
+            part2 = "12"
+
+            [day25."input.txt"]
+            part1 = "2=-1=0"
+        "#).unwrap();
+
+        assert_eq!(registry.entries().collect::<Vec<_>>(), vec![
+            &Entry { day: 2, input: "sample.txt".to_string(), answers: vec![(Part::One, "15".to_string()), (Part::Two, "12".to_string())] },
+            &Entry { day: 25, input: "input.txt".to_string(), answers: vec![(Part::One, "2=-1=0".to_string())] }
+        ]);
+    }
+
+    #[test]
+    fn reject_bad_keys() {
+        assert!(Registry::parse("[dayX.\"input.txt\"]\npart1 = \"1\"").is_err());
+        assert!(Registry::parse("[day01.\"input.txt\"]\npart3 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn repo_answers_parse() {
+        let registry = Registry::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml")).unwrap();
+        assert!(registry.entries().all(|entry| (1..=crate::last_day()).contains(&entry.day)));
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use utils::error::InputError;
+use utils::input::Input;
+use utils::parallel;
+use utils::solution::{Part, Solution};
+use crate::answers::Registry;
+use crate::{bencher, day_dir, repo_dir, Args};
+
+// Each stage is repeated until it has run this many times or for this long, whichever comes first,
+// so quick days get a stable median and slow ones (day 19) only run once.
+const MAX_RUNS: usize = 100;
+const TIME_BUDGET: Duration = Duration::from_secs(1);
+
+/// The median time to parse an input and to solve each part from the parsed input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    #[serde(with = "nanos")]
+    pub parse: Duration,
+    #[serde(with = "nanos::option", default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Duration>,
+    #[serde(with = "nanos::option", default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Duration>
+}
+
+impl Timings {
+    fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+
+    // The total of just the stages `other` timed too, so a run of one part compares fairly
+    // against a baseline of both.
+    fn total_like(&self, other: &Timings) -> Duration {
+        let only = |mine: Option<Duration>, theirs: Option<Duration>| theirs.and(mine).unwrap_or_default();
+        self.parse + only(self.part1, other.part1) + only(self.part2, other.part2)
+    }
+}
+
+/// A type-erased `measure::<S>`, alongside each day's `Solver`.
+pub type Bencher = fn(&Input, &[Part]) -> Result<Timings, InputError>;
+
+/// Time `S::parse` and each of `parts` separately.
+pub fn measure<S: Solution>(input: &Input, parts: &[Part]) -> Result<Timings, InputError> {
+    let parsed = S::parse(input).map_err(|err| input.locate(err))?;
+    let mut timings = Timings { parse: median_time(|| { black_box(S::parse(input)).ok(); }), ..Timings::default() };
+    for &part in parts {
+        match part {
+            Part::One => timings.part1 = Some(median_time(|| { black_box(S::part1(&parsed)); })),
+            Part::Two => timings.part2 = Some(median_time(|| { black_box(S::part2(&parsed)); }))
+        }
+    }
+    Ok(timings)
+}
+
+fn median_time(mut run: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    let mut times = vec![];
+    while times.is_empty() || times.len() < MAX_RUNS && start.elapsed() < TIME_BUDGET {
+        let run_start = Instant::now();
+        run();
+        times.push(run_start.elapsed());
+    }
+    times.sort();
+    times[times.len() / 2]
+}
+
+// Saved timings, keyed like answers.toml: `[day16."input.txt"]`.
+type Baseline = BTreeMap<String, BTreeMap<String, Timings>>;
+
+fn baseline_path(name: &str) -> PathBuf {
+    repo_dir().join(".bench").join(format!["{name}.toml"])
+}
+
+fn load_baseline(name: &str) -> Result<Baseline, String> {
+    let path = baseline_path(name);
+    let text = std::fs::read_to_string(&path).map_err(|e| format!["Couldn't read baseline \"{}\": {e}", path.display()])?;
+    toml::from_str(&text).map_err(|e| format!["Couldn't parse baseline \"{}\": {e}", path.display()])
+}
+
+fn save_baseline(name: &str, baseline: &Baseline) -> Result<PathBuf, String> {
+    let path = baseline_path(name);
+    let text = toml::to_string(baseline).map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| std::fs::write(&path, text))
+        .map_err(|e| format!["Couldn't save baseline \"{}\": {e}", path.display()])?;
+    Ok(path)
+}
+
+/// Time every input in `registry` selected by `args` (only the parts with known answers, since the
+/// others may not finish) and print a table, optionally comparing against and saving baselines.
+/// With `--threads`, each input is timed on one thread too, to show how much the threads sped it up.
+/// Returns whether every input could be timed.
+pub fn bench(registry: &Registry, args: &Args, save: Option<&str>, baseline: Option<&str>) -> bool {
+    let previous = match baseline.map(load_baseline).transpose() {
+        Ok(previous) => previous,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    println!(
+        "{:>3}  {:<16} {:>10} {:>10} {:>10} {:>10}{}{}",
+        "Day", "Input", "Parse", "Part 1", "Part 2", "Total",
+        if previous.is_some() { "  vs baseline" } else { "" },
+        if args.threads.is_some() { "  vs 1 thread" } else { "" }
+    );
+
+    let mut all_timed = true;
+    let mut results = Baseline::new();
+    let selected = registry.entries().filter(|entry|
+        args.days.contains(&entry.day) && args.input.as_ref().is_none_or(|input| *input == entry.input)
+    );
+    for entry in selected {
+        let parts: Vec<Part> = entry.answers.iter().map(|(part, _)| *part).filter(|part| args.parts.contains(part)).collect();
+        if parts.is_empty() {
+            continue;
+        }
+
+        let path = day_dir(entry.day).join(&entry.input);
+        let result = catch_unwind(AssertUnwindSafe(|| Input::load(&path.to_string_lossy()).and_then(|input| {
+            let timings = bencher(entry.day)(&input, &parts)?;
+            let serial = match args.threads {
+                Some(_) => Some(parallel::with_threads(1, || bencher(entry.day)(&input, &parts)).map_err(InputError::new)??),
+                None => None
+            };
+            Ok((timings, serial))
+        })));
+        let (timings, serial) = match result {
+            Ok(Ok(timings)) => timings,
+            Ok(Err(err)) => {
+                println!("{:>3}  {:<16} FAIL: {err}", entry.day, entry.input);
+                all_timed = false;
+                continue;
+            },
+            Err(_) => {
+                println!("{:>3}  {:<16} FAIL: panicked", entry.day, entry.input);
+                all_timed = false;
+                continue;
+            }
+        };
+
+        let key = format!["day{:02}", entry.day];
+        let before = previous.as_ref().and_then(|previous| previous.get(&key)?.get(&entry.input));
+        println!(
+            "{:>3}  {:<16} {:>10} {:>10} {:>10} {:>10}{}{}",
+            entry.day,
+            entry.input,
+            format!["{:.1?}", timings.parse],
+            timings.part1.map_or("-".to_string(), |t| format!["{t:.1?}"]),
+            timings.part2.map_or("-".to_string(), |t| format!["{t:.1?}"]),
+            format!["{:.1?}", timings.total()],
+            match (&previous, before) {
+                (None, _) => String::new(),
+                (Some(_), None) => format!["  {:>12}", "new"],
+                (Some(_), Some(before)) => format!["  {:>12}", change(before.total_like(&timings), timings.total())]
+            },
+            serial.map_or(String::new(), |serial| format!["  {:>12}", speedup(serial.total(), timings.total())])
+        );
+        results.entry(key).or_default().insert(entry.input.clone(), timings);
+    }
+
+    let total: Duration = results.values().flat_map(|inputs| inputs.values()).map(Timings::total).sum();
+    println!("Total: {total:.1?}");
+
+    if let Some(name) = save {
+        match save_baseline(name, &results) {
+            Ok(path) => println!("Saved baseline to {}", path.display()),
+            Err(err) => {
+                eprintln!("{err}");
+                all_timed = false;
+            }
+        }
+    }
+    all_timed
+}
+
+// How much faster or slower, e.g. "-12.5%" for faster.
+fn change(before: Duration, after: Duration) -> String {
+    let percent = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    format!["{percent:+.1}%"]
+}
+
+// How many times faster, e.g. "3.2x".
+fn speedup(serial: Duration, parallel: Duration) -> String {
+    format!["{:.1}x", serial.as_secs_f64() / parallel.as_secs_f64()]
+}
+
+// Durations are saved as whole nanoseconds, which TOML can represent exactly.
+mod nanos {
+    use std::time::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+
+    pub mod option {
+        use std::time::Duration;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(&duration.unwrap_or_default(), serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+            Option::<u64>::deserialize(deserializer).map(|nanos| nanos.map(Duration::from_nanos))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baselines_round_trip() {
+        let timings = Timings { parse: Duration::from_micros(15), part1: None, part2: Some(Duration::from_nanos(1_234_567)) };
+        let baseline = Baseline::from([("day16".to_string(), BTreeMap::from([("input.txt".to_string(), timings)]))]);
+        let text = toml::to_string(&baseline).unwrap();
+        assert_eq!(text, "[day16.\"input.txt\"]\nparse = 15000\npart2 = 1234567\n");
+        assert_eq!(toml::from_str::<Baseline>(&text).unwrap(), baseline);
+    }
+
+    #[test]
+    fn compare_the_same_stages() {
+        let both = Timings { parse: Duration::from_millis(1), part1: Some(Duration::from_millis(2)), part2: Some(Duration::from_millis(4)) };
+        let one = Timings { part2: None, ..both.clone() };
+        assert_eq!((both.total(), both.total_like(&one)), (Duration::from_millis(7), Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn changes() {
+        assert_eq!(change(Duration::from_millis(200), Duration::from_millis(150)), "-25.0%");
+        assert_eq!(change(Duration::from_millis(100), Duration::from_millis(110)), "+10.0%");
+        assert_eq!(speedup(Duration::from_millis(300), Duration::from_millis(100)), "3.0x");
+    }
+
+    #[test]
+    fn measure_every_part() {
+        let timings = measure::<day01::Day01>(&Input::load(&day_dir(1).join("sample.txt").to_string_lossy()).unwrap(), &[Part::Two]).unwrap();
+        assert!(timings.part1.is_none() && timings.part2.is_some());
+    }
+}
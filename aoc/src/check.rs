@@ -0,0 +1,116 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use utils::input::Input;
+use utils::solution::{Part, PartAnswer};
+use crate::answers::{Entry, Registry};
+use crate::{day_dir, solver, Args};
+
+enum Outcome {
+    Pass,
+    Mismatch { expected: String, got: String },
+    Fail(String)
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    mismatched: usize,
+    failed: usize
+}
+
+impl Tally {
+    fn report(&mut self, day: u8, part: Part, input: &str, outcome: Outcome, elapsed: Duration) {
+        let label = format!["Day {day} part {part} ({input})"];
+        match outcome {
+            Outcome::Pass => {
+                self.passed += 1;
+                println!("{label}: pass [{elapsed:.1?}]");
+            },
+            Outcome::Mismatch { expected, got } => {
+                self.mismatched += 1;
+                println!("{label}: MISMATCH [{elapsed:.1?}]\n  expected: {}\n  got:      {}", indent(&expected), indent(&got));
+            },
+            Outcome::Fail(reason) => {
+                self.failed += 1;
+                println!("{label}: FAIL: {reason}");
+            }
+        }
+    }
+
+    fn all_passed(&self) -> bool {
+        self.mismatched == 0 && self.failed == 0
+    }
+}
+
+// Line up the rest of a multi-line answer (day 10's CRT) under its first line.
+fn indent(answer: &str) -> String {
+    answer.replace('\n', "\n            ")
+}
+
+/// Solve every entry in `registry` selected by `args` and compare against the expected answers,
+/// printing each result with how long it took. Parsing time is included in the first part solved
+/// for each input. Returns whether everything passed.
+pub fn check(registry: &Registry, args: &Args) -> bool {
+    let start = Instant::now();
+    let mut tally = Tally::default();
+
+    let selected = registry.entries().filter(|entry|
+        args.days.contains(&entry.day) && args.input.as_ref().is_none_or(|input| *input == entry.input)
+    );
+    for entry in selected {
+        check_entry(entry, &args.parts, &mut tally);
+    }
+
+    let checked = tally.passed + tally.mismatched + tally.failed;
+    println!(
+        "Checked {checked} answers in {:.1?}: {} passed, {} mismatched, {} failed",
+        start.elapsed(), tally.passed, tally.mismatched, tally.failed
+    );
+    tally.all_passed()
+}
+
+fn check_entry(entry: &Entry, parts: &[Part], tally: &mut Tally) {
+    let expected: Vec<&(Part, String)> = entry.answers.iter().filter(|(part, _)| parts.contains(part)).collect();
+    let parts: Vec<Part> = expected.iter().map(|(part, _)| *part).collect();
+    if parts.is_empty() {
+        return;
+    }
+
+    let path = day_dir(entry.day).join(&entry.input);
+    let input = match Input::load(&path.to_string_lossy()) {
+        Ok(input) => input,
+        Err(err) => {
+            for &part in &parts {
+                tally.report(entry.day, part, &entry.input, Outcome::Fail(err.to_string()), Duration::ZERO);
+            }
+            return;
+        }
+    };
+
+    let mut solved = vec![];
+    let mut last = Instant::now();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        solver(entry.day)(&input, &parts, &mut |PartAnswer { part, answer }| {
+            let elapsed = last.elapsed();
+            let (_, want) = expected.iter().find(|(p, _)| *p == part).unwrap();
+            let outcome = if answer == *want {
+                Outcome::Pass
+            } else {
+                Outcome::Mismatch { expected: want.clone(), got: answer }
+            };
+            tally.report(entry.day, part, &entry.input, outcome, elapsed);
+            solved.push(part);
+            last = Instant::now();
+        })
+    }));
+
+    // Bad input or a panic loses the part that was being solved and any after it.
+    let reason = match result {
+        Ok(Ok(())) => return,
+        Ok(Err(err)) => err.to_string(),
+        Err(_) => "panicked".to_string()
+    };
+    for &part in parts.iter().filter(|part| !solved.contains(part)) {
+        tally.report(entry.day, part, &entry.input, Outcome::Fail(reason.clone()), Duration::ZERO);
+    }
+}
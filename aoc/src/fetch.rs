@@ -0,0 +1,87 @@
+use std::ops::RangeInclusive;
+use std::path::Path;
+use utils::fetch::{Fetcher, Http, Ureq};
+use crate::day_dir;
+
+const YEAR: u16 = 2022;
+
+/// Download each day's input into its input.txt and the puzzle's first example into its sample.txt,
+/// leaving alone any that already have something in them. Returns whether every day was fetched.
+pub fn fetch(days: RangeInclusive<u8>) -> bool {
+    let mut fetcher = Fetcher::from_env(Ureq);
+    let mut all_fetched = true;
+    for day in days {
+        match fetch_day(&mut fetcher, day, &day_dir(day)) {
+            Ok(written) if written.is_empty() => println!("Day {day}: already has its input and sample"),
+            Ok(written) => println!("Day {day}: wrote {}", written.join(" and ")),
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                all_fetched = false;
+            }
+        }
+    }
+    all_fetched
+}
+
+// The files written into `dir`.
+fn fetch_day<H: Http>(fetcher: &mut Fetcher<H>, day: u8, dir: &Path) -> Result<Vec<&'static str>, String> {
+    let mut written = vec![];
+    if is_empty(&dir.join("input.txt")) {
+        let input = fetcher.input(YEAR, day).map_err(|e| e.to_string())?;
+        write(&dir.join("input.txt"), &input)?;
+        written.push("input.txt");
+    }
+    if is_empty(&dir.join("sample.txt")) {
+        let examples = fetcher.examples(YEAR, day).map_err(|e| e.to_string())?;
+        let example = examples.first().ok_or("The puzzle page has no examples")?;
+        write(&dir.join("sample.txt"), example)?;
+        written.push("sample.txt");
+    }
+    Ok(written)
+}
+
+fn is_empty(path: &Path) -> bool {
+    std::fs::metadata(path).map_or(true, |metadata| metadata.len() == 0)
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    std::fs::write(path, text).map_err(|e| format!["Couldn't write \"{}\": {e}", path.display()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use utils::fetch::FetchError;
+
+    struct Site;
+
+    impl Http for Site {
+        fn get(&self, url: &str, _session: &str) -> Result<String, FetchError> {
+            match url.strip_prefix("https://adventofcode.com/2022/day/") {
+                Some("1/input") => Ok("1000\n".to_string()),
+                Some("1") => Ok("<pre><code>1\n2\n</code></pre>".to_string()),
+                _ => Err(FetchError::Status { url: url.to_string(), status: 404 })
+            }
+        }
+    }
+
+    #[test]
+    fn fills_in_empty_files() {
+        let cache = tempfile::tempdir().unwrap();
+        let day = tempfile::tempdir().unwrap();
+        std::fs::write(day.path().join("sample.txt"), "").unwrap();
+        let mut fetcher = Fetcher::new(Site, Some("abc123".to_string()), cache.path()).min_interval(Duration::ZERO);
+
+        assert_eq!(fetch_day(&mut fetcher, 1, day.path()), Ok(vec!["input.txt", "sample.txt"]));
+        assert_eq!(std::fs::read_to_string(day.path().join("sample.txt")).unwrap(), "1\n2\n");
+        assert_eq!(fetch_day(&mut fetcher, 1, day.path()), Ok(vec![]));
+
+        std::fs::write(day.path().join("input.txt"), "mine\n").unwrap();
+        std::fs::remove_file(day.path().join("sample.txt")).unwrap();
+        assert_eq!(fetch_day(&mut fetcher, 1, day.path()), Ok(vec!["sample.txt"]));
+        assert_eq!(std::fs::read_to_string(day.path().join("input.txt")).unwrap(), "mine\n");
+
+        assert!(fetch_day(&mut fetcher, 2, tempfile::tempdir().unwrap().path()).is_err());
+    }
+}
@@ -0,0 +1,523 @@
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use utils::generate::{generate, Generator};
+use utils::input::Input;
+use utils::solution::{solve, Part, Solver};
+use utils::validate::{violations, Validator, Violation};
+use answers::Registry;
+use bench::{measure, Bencher};
+use output::{Format, Output, Record};
+
+mod answers;
+mod bench;
+mod check;
+mod fetch;
+mod new;
+mod output;
+
+const USAGE: &str = "Usage: aoc <day|all|first-last> [--part 1|2] [--threads n] [--log filter] [--json|--ndjson] [input]
+       aoc check [day|all|first-last] [--part 1|2] [--threads n] [--log filter] [input]
+       aoc validate [day|all|first-last] [input]
+       aoc bench [day|all|first-last] [--part 1|2] [--threads n] [--log filter] [input] [--save name] [--baseline name]
+       aoc new <day> [--lalrpop|--scan-fmt]
+       aoc fetch <day|all|first-last>
+       aoc generate <day> [--seed n] [name=value ...]
+
+  check       compare answers against answers.toml, with timings
+  validate    check inputs against what each day's solver assumes about them, reporting any it
+              breaks (solving checks them too, and skips a day whose input breaks one)
+  bench       time parsing and each part for every input in answers.toml, and print a table
+  --save      save the timings as a named baseline
+  --baseline  compare the timings against a saved baseline
+  new         create dayNN from the dayXX template and register it here and in answers.toml,
+              optionally parsing with a LALRPOP grammar or scan_fmt
+  fetch       download empty input.txt and sample.txt files, using the session cookie in AOC_SESSION
+              and caching downloads in AOC_CACHE (default: ~/.cache/aoc)
+  generate    print a random input for a day, as big as its name=value params say (an unknown name
+              lists them); the seed is printed to stderr, and --seed makes the same input again
+
+  day         a single day, e.g. `16`
+  all         every day
+  first-last  an inclusive range of days, e.g. `20-25`
+  --part      only solve one part (default: both)
+  --threads   how many threads days 15, 16 and 19 use when built with the parallel feature (default:
+              one per core); bench also times each input on one thread and shows the speedup
+  input       the input file (default: input.txt in the day's directory), or - for stdin
+  --json      print the answers and elapsed times (including parsing, for the first part), and any
+              diagnostics the solvers log at info or above, as a JSON array of records
+  --ndjson    the same records, one per line, as they happen
+  --log       which solver logs to print to stderr, e.g. `day11=debug` or `trace` (default: AOC_LOG)";
+
+const SOLUTIONS: &[(u8, Solver, Bencher)] = &[
+    (1, solve::<day01::Day01>, measure::<day01::Day01>),
+    (2, solve::<day02::Day02>, measure::<day02::Day02>),
+    (3, solve::<day03::Day03>, measure::<day03::Day03>),
+    (4, solve::<day04::Day04>, measure::<day04::Day04>),
+    (5, solve::<day05::Day05>, measure::<day05::Day05>),
+    (6, solve::<day06::Day06>, measure::<day06::Day06>),
+    (7, solve::<day07::Day07>, measure::<day07::Day07>),
+    (8, solve::<day08::Day08>, measure::<day08::Day08>),
+    (9, solve::<day09::Day09>, measure::<day09::Day09>),
+    (10, solve::<day10::Day10>, measure::<day10::Day10>),
+    (11, solve::<day11::Day11>, measure::<day11::Day11>),
+    (12, solve::<day12::Day12>, measure::<day12::Day12>),
+    (13, solve::<day13::Day13>, measure::<day13::Day13>),
+    (14, solve::<day14::Day14>, measure::<day14::Day14>),
+    (15, solve::<day15::Day15>, measure::<day15::Day15>),
+    (16, solve::<day16::Day16>, measure::<day16::Day16>),
+    (17, solve::<day17::Day17>, measure::<day17::Day17>),
+    (18, solve::<day18::Day18>, measure::<day18::Day18>),
+    (19, solve::<day19::Day19>, measure::<day19::Day19>),
+    (20, solve::<day20::Day20>, measure::<day20::Day20>),
+    (21, solve::<day21::Day21>, measure::<day21::Day21>),
+    (22, solve::<day22::Day22>, measure::<day22::Day22>),
+    (23, solve::<day23::Day23>, measure::<day23::Day23>),
+    (24, solve::<day24::Day24>, measure::<day24::Day24>),
+    (25, solve::<day25::Day25>, measure::<day25::Day25>),
+];
+
+// The days that can make up inputs for `aoc generate`.
+const GENERATORS: &[(u8, Generator)] = &[
+    (16, generate::<day16::Day16>),
+    (18, generate::<day18::Day18>),
+    (19, generate::<day19::Day19>),
+    (21, generate::<day21::Day21>),
+    (24, generate::<day24::Day24>),
+];
+
+// The days that declare what they assume about their inputs, for `aoc validate`.
+const VALIDATORS: &[(u8, Validator)] = &[
+    (16, violations::<day16::Day16>),
+    (20, violations::<day20::Day20>),
+    (21, violations::<day21::Day21>),
+    (22, violations::<day22::Day22>),
+    (24, violations::<day24::Day24>),
+];
+
+fn main() {
+    let args = parse_args(utils::args_iter()).unwrap_or_else(|err| {
+        eprintln!("{err}\n\n{USAGE}");
+        std::process::exit(2);
+    });
+    let output = Output::new(args.format);
+    if let Err(err) = utils::trace::init_with(args.log.as_deref(), output.diagnostics()) {
+        eprintln!("{err}");
+        std::process::exit(2);
+    }
+    if let Err(err) = args.threads.map_or(Ok(()), utils::parallel::set_threads) {
+        eprintln!("{err}");
+        std::process::exit(2);
+    }
+
+    let passed = match &args.mode {
+        Mode::Solve => run(&args, &output),
+        Mode::Check => check::check(&load_registry(), &args),
+        Mode::Validate => validate_inputs(&args),
+        Mode::Bench { save, baseline } => bench::bench(&load_registry(), &args, save.as_deref(), baseline.as_deref()),
+        Mode::New { parser } => new::new_day(*args.days.start(), *parser).map_err(|err| eprintln!("{err}")).is_ok(),
+        Mode::Fetch => fetch::fetch(args.days.clone()),
+        Mode::Generate { seed, params } => generate_input(*args.days.start(), *seed, params)
+    };
+    output.finish();
+    if !passed {
+        std::process::exit(1);
+    }
+}
+
+// Solve the selected days and print the answers. Returns whether every input parsed.
+fn run(args: &Args, output: &Output) -> bool {
+    let mut all_parsed = true;
+    let input_name = args.input.as_deref().unwrap_or("input.txt");
+    for &(day, solver, _) in SOLUTIONS.iter().filter(|(day, _, _)| args.days.contains(day)) {
+        let path = input_path(day, args);
+        let _input = tracing::info_span!("input", input = input_name).entered();
+        let result = Input::load(&path.to_string_lossy()).and_then(|input| {
+            let violations = violations_of(day, &input);
+            if !violations.is_empty() {
+                report_violations(day, &violations);
+                all_parsed = false;
+                return Ok(());
+            }
+            let mut start = Instant::now();
+            solver(&input, &args.parts, &mut |answer| {
+                match output.format() {
+                    Format::Text => println!("Day {day} {answer}"),
+                    _ => output.emit(Record::answer(day, answer.part.number(), input_name, answer.answer, start.elapsed()))
+                }
+                start = Instant::now();
+            })
+        });
+        if let Err(err) = result {
+            eprintln!("Day {day}: {err}");
+            all_parsed = false;
+        }
+    }
+    all_parsed
+}
+
+// Check each selected day's input against its assumptions. Returns whether every input holds to them
+// and parses.
+fn validate_inputs(args: &Args) -> bool {
+    let mut all_valid = true;
+    for &(day, solver, _) in SOLUTIONS.iter().filter(|(day, _, _)| args.days.contains(day)) {
+        if args.days.start() != args.days.end() && !VALIDATORS.iter().any(|(d, _)| *d == day) {
+            continue;
+        }
+        let path = input_path(day, args);
+        let result = Input::load(&path.to_string_lossy()).and_then(|input| {
+            let violations = violations_of(day, &input);
+            if violations.is_empty() {
+                solver(&input, &[], &mut |_| ())?;
+            }
+            Ok(violations)
+        });
+        match result {
+            Ok(violations) if violations.is_empty() => println!("Day {day}: ok"),
+            Ok(violations) => {
+                report_violations(day, &violations);
+                all_valid = false;
+            },
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                all_valid = false;
+            }
+        }
+    }
+    all_valid
+}
+
+fn violations_of(day: u8, input: &Input) -> Vec<Violation> {
+    VALIDATORS.iter().find(|(d, _)| *d == day).map_or(vec![], |(_, validator)| validator(input))
+}
+
+fn report_violations(day: u8, violations: &[Violation]) {
+    eprintln!("Day {day}: the input breaks what the solver assumes:");
+    for violation in violations {
+        eprintln!("  {violation}");
+    }
+}
+
+// Print a made-up input for `day`. The seed goes to stderr, so an input that breaks something can be
+// made again.
+fn generate_input(day: u8, seed: Option<u64>, params: &[String]) -> bool {
+    let Some(&(_, generator)) = GENERATORS.iter().find(|(d, _)| *d == day) else {
+        let days: Vec<String> = GENERATORS.iter().map(|(d, _)| d.to_string()).collect();
+        eprintln!("Day {day} has no generator. These days do: {}", days.join(", "));
+        return false;
+    };
+    let seed = seed.unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64);
+    match generator(seed, params) {
+        Ok(input) => {
+            eprintln!("Day {day}, seed {seed}");
+            print!("{input}");
+            true
+        },
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        }
+    }
+}
+
+fn load_registry() -> Registry {
+    Registry::load(&repo_dir().join("answers.toml")).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        std::process::exit(2);
+    })
+}
+
+fn solver(day: u8) -> Solver {
+    SOLUTIONS.iter().find(|(d, _, _)| *d == day).map(|(_, solver, _)| *solver).unwrap()
+}
+
+fn bencher(day: u8) -> Bencher {
+    SOLUTIONS.iter().find(|(d, _, _)| *d == day).map(|(_, _, bencher)| *bencher).unwrap()
+}
+
+#[derive(Debug, PartialEq)]
+enum Mode {
+    Solve,
+    Check,
+    Validate,
+    Bench { save: Option<String>, baseline: Option<String> },
+    New { parser: new::Parser },
+    Fetch,
+    Generate { seed: Option<u64>, params: Vec<String> }
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    mode: Mode,
+    days: RangeInclusive<u8>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    log: Option<String>,
+    format: Format,
+    threads: Option<usize>
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.peekable();
+    if args.next_if_eq("new").is_some() {
+        return parse_new_args(args);
+    }
+    if args.next_if_eq("generate").is_some() {
+        return parse_generate_args(args);
+    }
+    if args.next_if_eq("fetch").is_some() {
+        let days = parse_days(&args.next().ok_or("Missing day")?)?;
+        if let Some(arg) = args.next() {
+            return Err(format!["Unexpected argument \"{arg}\""]);
+        }
+        return Ok(Args { mode: Mode::Fetch, days, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None });
+    }
+    let mut mode = match args.next_if(|arg| arg == "check" || arg == "validate" || arg == "bench").as_deref() {
+        Some("check") => Mode::Check,
+        Some("validate") => Mode::Validate,
+        Some(_) => Mode::Bench { save: None, baseline: None },
+        None => Mode::Solve
+    };
+    let all_by_default = mode != Mode::Solve;
+    // Modes that default to all days only take a day argument that looks like one (a backwards range
+    // does, so it gets its error rather than being mistaken for an input file).
+    let is_days = |arg: &String| parse_days(arg).map_or_else(|err| err.starts_with("Invalid day range"), |_| true);
+    let days = match args.next_if(|arg| !all_by_default || !arg.starts_with("--") && is_days(arg)) {
+        Some(arg) => parse_days(&arg)?,
+        None if all_by_default => 1..=last_day(),
+        None => return Err("Missing day".to_string())
+    };
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = None;
+    let mut log = None;
+    let mut format = Format::Text;
+    let mut threads = None;
+
+    while let Some(arg) = args.next() {
+        if let (Mode::Solve, "--json" | "--ndjson") = (&mode, arg.as_str()) {
+            format = if arg == "--json" { Format::Json } else { Format::Ndjson };
+        } else if arg == "--log" && mode != Mode::Validate {
+            log = Some(args.next().ok_or("Missing filter after --log")?);
+        } else if arg == "--threads" && mode != Mode::Validate {
+            let count = args.next().ok_or("Missing count after --threads")?;
+            threads = Some(count.parse().ok().filter(|&count| count > 0).ok_or(format!["Invalid thread count \"{count}\""])?);
+        } else if arg == "--part" && mode != Mode::Validate {
+            let part = args.next().ok_or("Missing part after --part")?;
+            let part = part.parse().ok().and_then(Part::from_number).ok_or(format!["Invalid part \"{part}\""])?;
+            parts = vec![part];
+        } else if let (Mode::Bench { save, baseline }, "--save" | "--baseline") = (&mut mode, arg.as_str()) {
+            let name = args.next().ok_or(format!["Missing name after {arg}"])?;
+            *(if arg == "--save" { save } else { baseline }) = Some(name);
+        } else if input.is_none() && !arg.starts_with("--") {
+            input = Some(arg);
+        } else {
+            return Err(format!["Unexpected argument \"{arg}\""]);
+        }
+    }
+
+    Ok(Args { mode, days, parts, input, log, format, threads })
+}
+
+// `aoc new` takes any day that doesn't have a solution yet.
+fn parse_new_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let day = args.next().ok_or("Missing day")?;
+    let day = match day.parse::<u8>() {
+        Ok(day) if day > 0 && !SOLUTIONS.iter().any(|(d, _, _)| *d == day) => day,
+        _ => return Err(format!["Invalid new day \"{day}\""])
+    };
+    let parser = match args.next().as_deref() {
+        None => new::Parser::Lines,
+        Some("--lalrpop") => new::Parser::Lalrpop,
+        Some("--scan-fmt") => new::Parser::ScanFmt,
+        Some(arg) => return Err(format!["Unexpected argument \"{arg}\""])
+    };
+    if let Some(arg) = args.next() {
+        return Err(format!["Unexpected argument \"{arg}\""]);
+    }
+    Ok(Args { mode: Mode::New { parser }, days: day..=day, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None })
+}
+
+fn parse_generate_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let days = parse_days(&args.next().ok_or("Missing day")?)?;
+    if days.start() != days.end() {
+        return Err("Generate one day at a time".to_string());
+    }
+    let mut seed = None;
+    let mut params = vec![];
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            let value = args.next().ok_or("Missing number after --seed")?;
+            seed = Some(value.parse().map_err(|_| format!["Invalid seed \"{value}\""])?);
+        } else if arg.contains('=') {
+            params.push(arg);
+        } else {
+            return Err(format!["Unexpected argument \"{arg}\""]);
+        }
+    }
+    Ok(Args { mode: Mode::Generate { seed, params }, days, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None })
+}
+
+fn last_day() -> u8 {
+    SOLUTIONS.iter().map(|(day, _, _)| *day).max().unwrap()
+}
+
+fn parse_days(arg: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |s: &str| match s.parse::<u8>() {
+        Ok(day) if (1..=last_day()).contains(&day) => Ok(day),
+        _ => Err(format!["Invalid day \"{s}\""])
+    };
+
+    if arg == "all" {
+        return Ok(1..=last_day());
+    }
+    if let Some((first, last)) = arg.split_once('-') {
+        let (first_day, last_day) = (parse_day(first)?, parse_day(last)?);
+        if first_day > last_day {
+            return Err(format!["Invalid day range \"{arg}\": {first_day} comes after {last_day}"]);
+        }
+        return Ok(first_day..=last_day);
+    }
+    let day = parse_day(arg)?;
+    Ok(day..=day)
+}
+
+// A path given for a single day is used as-is if it exists (or is `-`, for stdin). Otherwise (and always
+// for multiple days) it names a file in each day's directory, so `aoc all sample.txt` runs every day's sample.
+fn input_path(day: u8, args: &Args) -> PathBuf {
+    let file = args.input.as_deref().unwrap_or("input.txt");
+    let single_day = args.days.start() == args.days.end();
+    if single_day && (file == "-" || Path::new(file).exists()) {
+        return PathBuf::from(file);
+    }
+    day_dir(day).join(file)
+}
+
+fn repo_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    repo_dir().join(format!["day{day:02}"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<Args, String> {
+        parse_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parse_day_ranges() {
+        assert_eq!(parse_days("16"), Ok(16..=16));
+        assert_eq!(parse_days("all"), Ok(1..=last_day()));
+        assert_eq!(parse_days("20-25"), Ok(20..=25));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days(&(last_day() + 1).to_string()).is_err());
+        assert!(parse_days("1-x").is_err());
+        assert_eq!(parse_days("20-10"), Err("Invalid day range \"20-10\": 20 comes after 10".to_string()));
+        assert_eq!(parse_days("7-7"), Ok(7..=7));
+    }
+
+    #[test]
+    fn parse_full_args() {
+        assert_eq!(args("16 --part 2 input.txt"), Ok(Args { mode: Mode::Solve, days: 16..=16, parts: vec![Part::Two], input: Some("input.txt".to_string()), log: None, format: Format::Text, threads: None }));
+        assert_eq!(args("all"), Ok(Args { mode: Mode::Solve, days: 1..=last_day(), parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None }));
+        assert!(args("").is_err());
+        assert!(args("1 --part 3").is_err());
+        assert_eq!(args("11 --log day11=debug").map(|args| args.log), Ok(Some("day11=debug".to_string())));
+        assert!(args("11 --log").is_err());
+        assert_eq!(args("17 --ndjson").map(|args| args.format), Ok(Format::Ndjson));
+        assert_eq!(args("17 --json sample.txt").map(|args| (args.format, args.input)), Ok((Format::Json, Some("sample.txt".to_string()))));
+        assert!(args("check --json").is_err());
+        assert!(args("1 a.txt b.txt").is_err());
+        assert_eq!(args("19 --threads 4").map(|args| args.threads), Ok(Some(4)));
+        assert!(args("19 --threads 0").is_err());
+        assert!(args("19 --threads").is_err());
+        assert!(args("validate --threads 2").is_err());
+    }
+
+    #[test]
+    fn parse_check_args() {
+        assert_eq!(args("check"), Ok(Args { mode: Mode::Check, days: 1..=last_day(), parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None }));
+        assert_eq!(args("check 3-5 --part 1"), Ok(Args { mode: Mode::Check, days: 3..=5, parts: vec![Part::One], input: None, log: None, format: Format::Text, threads: None }));
+        assert_eq!(args("check 5-3"), Err("Invalid day range \"5-3\": 5 comes after 3".to_string()));
+        assert_eq!(args("check sample.txt"), Ok(Args { mode: Mode::Check, days: 1..=last_day(), parts: Part::BOTH.to_vec(), input: Some("sample.txt".to_string()), log: None, format: Format::Text, threads: None }));
+    }
+
+    #[test]
+    fn parse_validate_args() {
+        assert_eq!(args("validate"), Ok(Args { mode: Mode::Validate, days: 1..=last_day(), parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None }));
+        assert_eq!(args("validate 22 sample.txt"), Ok(Args { mode: Mode::Validate, days: 22..=22, parts: Part::BOTH.to_vec(), input: Some("sample.txt".to_string()), log: None, format: Format::Text, threads: None }));
+        assert!(args("validate --part 1").is_err());
+        assert!(args("validate --json").is_err());
+    }
+
+    #[test]
+    fn sample_inputs_are_valid() {
+        for &(day, validator) in VALIDATORS {
+            let input = Input::load(&day_dir(day).join("sample.txt").to_string_lossy()).unwrap();
+            assert_eq!(validator(&input), vec![], "Day {day}'s sample should be valid");
+        }
+    }
+
+    #[test]
+    fn parse_bench_args() {
+        let bench = |save: Option<&str>, baseline: Option<&str>| Mode::Bench { save: save.map(String::from), baseline: baseline.map(String::from) };
+        assert_eq!(args("bench"), Ok(Args { mode: bench(None, None), days: 1..=last_day(), parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None }));
+        assert_eq!(args("bench 16 --save before"), Ok(Args { mode: bench(Some("before"), None), days: 16..=16, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None }));
+        assert_eq!(
+            args("bench --baseline before input.txt --save after"),
+            Ok(Args { mode: bench(Some("after"), Some("before")), days: 1..=last_day(), parts: Part::BOTH.to_vec(), input: Some("input.txt".to_string()), log: None, format: Format::Text, threads: None })
+        );
+        assert!(args("bench --save").is_err());
+        assert!(args("check --save x").is_err());
+    }
+
+    #[test]
+    fn parse_generate_args() {
+        assert_eq!(
+            args("generate 16 --seed 42 valves=30"),
+            Ok(Args { mode: Mode::Generate { seed: Some(42), params: vec!["valves=30".to_string()] }, days: 16..=16, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None })
+        );
+        assert_eq!(args("generate 24").map(|args| args.mode), Ok(Mode::Generate { seed: None, params: vec![] }));
+        assert!(args("generate 20-24").is_err());
+        assert!(args("generate 16 --seed").is_err());
+        assert!(args("generate 16 --seed x").is_err());
+        assert!(args("generate 16 input.txt").is_err());
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for &(day, generator) in GENERATORS {
+            let input = Input::from(generator(1, &[]).unwrap().as_str());
+            assert!(solver(day)(&input, &[], &mut |_| ()).is_ok(), "Day {day}'s input should parse");
+        }
+    }
+
+    #[test]
+    fn every_day_has_a_solution() {
+        assert!(SOLUTIONS.iter().map(|(day, _, _)| *day).eq(1..=last_day()));
+    }
+
+    #[test]
+    fn parse_new_args() {
+        let new_day = last_day() + 1;
+        assert_eq!(
+            args(&format!["new {new_day} --lalrpop"]),
+            Ok(Args { mode: Mode::New { parser: new::Parser::Lalrpop }, days: new_day..=new_day, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None })
+        );
+        assert_eq!(args(&format!["new {new_day}"]).map(|args| args.mode), Ok(Mode::New { parser: new::Parser::Lines }));
+        assert!(args("new 1").is_err());
+        assert!(args("new 0").is_err());
+        assert!(args(&format!["new {new_day} --yacc"]).is_err());
+    }
+
+    #[test]
+    fn parse_fetch_args() {
+        assert_eq!(args("fetch 20-25"), Ok(Args { mode: Mode::Fetch, days: 20..=25, parts: Part::BOTH.to_vec(), input: None, log: None, format: Format::Text, threads: None }));
+        assert!(args("fetch").is_err());
+        assert!(args("fetch 1 input.txt").is_err());
+    }
+}
@@ -0,0 +1,214 @@
+use std::path::Path;
+use crate::{day_dir, repo_dir};
+
+/// How a new day's `parse` reads each line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parser {
+    /// The template as-is: each line as a `String`.
+    Lines,
+    /// A LALRPOP grammar in `src/parser.lalrpop`, with a `build.rs` to generate it.
+    Lalrpop,
+    /// A `scan_fmt!` pattern.
+    ScanFmt
+}
+
+// The template files copied into every new day, relative to dayXX.
+const TEMPLATE_FILES: [&str; 7] = [
+    ".gitignore",
+    ".vscode/launch.json",
+    "Cargo.toml",
+    "input.txt",
+    "sample.txt",
+    "src/lib.rs",
+    "src/main.rs"
+];
+
+// Appended to the template's Cargo.toml, which ends with an empty `[dependencies]` table.
+const LALRPOP_DEPENDENCIES: &str = r#"# lexer feature directive via: https://github.com/lalrpop/lalrpop/issues/650#issuecomment-1032308454
+lalrpop-util = { version = "^0.19", features = ["lexer"] }
+regex = "1"
+
+[build-dependencies]
+lalrpop = "0.19.7"
+"#;
+
+const LALRPOP_BUILD: &str = "use lalrpop;
+
+fn main() {
+  lalrpop::process_root().unwrap();
+}
+";
+
+const LALRPOP_GRAMMAR: &str = r#"use std::str::FromStr;
+use lalrpop_util::ParseError;
+
+grammar;
+
+// A line of whitespace-separated integers. Replace with the puzzle's format.
+pub Line: Vec<i64> = {
+  <Int64*>
+}
+
+Int64: i64 = {
+  r"-?[0-9]+" =>? i64::from_str(<>)
+    .map_err(|_| ParseError::User {
+      error: "Number can't be parsed"
+    })
+};
+"#;
+
+/// Create `dayNN` from the dayXX template, then register it with the runner (`aoc/Cargo.toml` and
+/// the `SOLUTIONS` table) and add a stub for its sample answers to answers.toml.
+pub fn new_day(day: u8, parser: Parser) -> Result<(), String> {
+    let template = repo_dir().join("dayXX");
+    let dir = day_dir(day);
+    if dir.exists() {
+        return Err(format!["{} already exists", dir.display()]);
+    }
+
+    for file in TEMPLATE_FILES {
+        let text = read(&template.join(file))?;
+        let text = if file == "src/lib.rs" { render_lib(&text, day, parser)? } else { rename(&text, day) };
+        write(&dir.join(file), &text)?;
+    }
+    match parser {
+        Parser::Lines => (),
+        Parser::Lalrpop => {
+            append(&dir.join("Cargo.toml"), LALRPOP_DEPENDENCIES)?;
+            write(&dir.join("build.rs"), LALRPOP_BUILD)?;
+            write(&dir.join("src/parser.lalrpop"), LALRPOP_GRAMMAR)?;
+        },
+        Parser::ScanFmt => append(&dir.join("Cargo.toml"), "scan_fmt = \"0.2.6\"\n")?
+    }
+
+    let aoc = repo_dir().join("aoc");
+    edit(&aoc.join("Cargo.toml"), |text| register_dependency(text, day))?;
+    edit(&aoc.join("src/main.rs"), |text| register_solution(text, day))?;
+    append(&repo_dir().join("answers.toml"), &format!["\n[day{day:02}.\"sample.txt\"]\n# part1 = \"\"\n# part2 = \"\"\n"])?;
+
+    println!("Created day{day:02}. Paste the example into sample.txt and your input into input.txt.");
+    Ok(())
+}
+
+fn rename(template: &str, day: u8) -> String {
+    template
+        .replace("dayXX", &format!["day{day:02}"])
+        .replace("DayXX", &format!["Day{day:02}"])
+}
+
+// The template's lib.rs, with `parse` swapped out for the chosen parser.
+fn render_lib(template: &str, day: u8, parser: Parser) -> Result<String, String> {
+    let lib = rename(template, day).replacen("const DAY: u8 = 0;", &format!["const DAY: u8 = {day};"], 1);
+    let (header, input_type, parse) = match parser {
+        Parser::Lines => return Ok(lib),
+        Parser::Lalrpop => (
+            "use lalrpop_util::*;\nlalrpop_mod!(pub parser);\n\n",
+            "Vec<Vec<i64>>",
+            "let parser = parser::LineParser::new();\n        input.parse_lines(|line| parser.parse(line))"
+        ),
+        Parser::ScanFmt => (
+            "#[macro_use] extern crate scan_fmt;\n",
+            "Vec<(String, i64)>",
+            "input.parse_lines(|line| scan_fmt!(line, \"{} {}\", String, i64))"
+        )
+    };
+
+    const INPUT_TYPE: &str = "type Input = Vec<String>;";
+    const PARSE: &str = "input.parse_lines(|line| Ok::<_, InputError>(line.to_string()))";
+    if !lib.contains(INPUT_TYPE) || !lib.contains(PARSE) {
+        return Err("dayXX/src/lib.rs no longer has the parse function `aoc new` replaces".to_string());
+    }
+    Ok(format![
+        "{header}{}",
+        lib.replacen(INPUT_TYPE, &format!["type Input = {input_type};"], 1).replacen(PARSE, parse, 1)
+    ])
+}
+
+// Add `dayNN = { path = "../dayNN" }` after the last day in the runner's dependencies.
+fn register_dependency(cargo_toml: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<&str> = cargo_toml.lines().collect();
+    let last_day = lines.iter().rposition(|line| line.starts_with("day")).ok_or("No day dependencies in aoc/Cargo.toml")?;
+    let dependency = format!["day{day:02} = {{ path = \"../day{day:02}\" }}"];
+    lines.insert(last_day + 1, &dependency);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Add the day to the `SOLUTIONS` table, keeping it in order.
+fn register_solution(main_rs: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<&str> = main_rs.lines().collect();
+    let start = lines.iter().position(|line| line.starts_with("const SOLUTIONS")).ok_or("No SOLUTIONS table in aoc/src/main.rs")?;
+    let end = start + lines[start..].iter().position(|line| *line == "];").ok_or("The SOLUTIONS table isn't closed")?;
+    let entry_day = |line: &str| line.trim().strip_prefix('(')?.split(',').next()?.parse::<u8>().ok();
+    let at = (start + 1..end).find(|&i| entry_day(lines[i]).is_some_and(|d| d > day)).unwrap_or(end);
+
+    let name = format!["day{day:02}::Day{day:02}"];
+    let entry = format!["    ({day}, solve::<{name}>, measure::<{name}>),"];
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!["Couldn't read \"{}\": {e}", path.display()])
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    std::fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| std::fs::write(path, text))
+        .map_err(|e| format!["Couldn't write \"{}\": {e}", path.display()])
+}
+
+fn append(path: &Path, text: &str) -> Result<(), String> {
+    let existing = read(path)?;
+    write(path, &(existing + text))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let text = f(&read(path)?)?;
+    write(path, &text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> String {
+        read(&repo_dir().join("dayXX/src/lib.rs")).unwrap()
+    }
+
+    #[test]
+    fn rename_the_template() {
+        let lib = render_lib(&template(), 7, Parser::Lines).unwrap();
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(!lib.contains("XX"));
+        assert_eq!(rename("cargo build --bin=dayXX", 26), "cargo build --bin=day26");
+    }
+
+    #[test]
+    fn swap_in_a_parser() {
+        let lib = render_lib(&template(), 26, Parser::Lalrpop).unwrap();
+        assert!(lib.starts_with("use lalrpop_util::*;\nlalrpop_mod!(pub parser);"));
+        assert!(lib.contains("type Input = Vec<Vec<i64>>;") && lib.contains("parser::LineParser::new()"));
+
+        let lib = render_lib(&template(), 26, Parser::ScanFmt).unwrap();
+        assert!(lib.starts_with("#[macro_use] extern crate scan_fmt;"));
+        assert!(lib.contains("scan_fmt!(line"));
+
+        assert!(render_lib("pub struct DayXX;", 26, Parser::ScanFmt).is_err());
+    }
+
+    #[test]
+    fn register_with_the_runner() {
+        let cargo_toml = "[dependencies]\nday01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\n";
+        assert_eq!(
+            register_dependency(cargo_toml, 26).unwrap(),
+            "[dependencies]\nday01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\nday26 = { path = \"../day26\" }\n"
+        );
+
+        let main_rs = "const SOLUTIONS: &[(u8, Solver, Bencher)] = &[\n    (1, solve::<day01::Day01>, measure::<day01::Day01>),\n    (3, solve::<day03::Day03>, measure::<day03::Day03>),\n];\n";
+        let registered = register_solution(main_rs, 2).unwrap();
+        assert_eq!(registered.lines().nth(2), Some("    (2, solve::<day02::Day02>, measure::<day02::Day02>),"));
+        let registered = register_solution(main_rs, 26).unwrap();
+        assert_eq!(registered.lines().nth(3), Some("    (26, solve::<day26::Day26>, measure::<day26::Day26>),"));
+    }
+}
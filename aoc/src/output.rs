@@ -0,0 +1,171 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{Layer, Registry};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON array of every record, printed at the end.
+    Json,
+    /// One record per line, printed as soon as it happens, so a run that's stopped early still has
+    /// everything up to that point.
+    Ndjson
+}
+
+/// What the runner prints in the JSON formats: each part's answer and how long it took, and the
+/// diagnostics the solvers logged along the way.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Record {
+    Answer { day: u8, part: u8, input: String, answer: String, elapsed_ns: u64 },
+    /// A `tracing` event at `info` or above. `--log` doesn't change which events these are, only what's
+    /// printed to stderr. `fields` has the event's fields, including its `message`, and those of the
+    /// spans it was in (`input`, `day` and `part`).
+    Diagnostic { level: String, fields: Map<String, Value> }
+}
+
+impl Record {
+    pub fn answer(day: u8, part: u8, input: &str, answer: String, elapsed: Duration) -> Self {
+        Record::Answer { day, part, input: input.to_string(), answer, elapsed_ns: elapsed.as_nanos() as u64 }
+    }
+}
+
+/// Where records go. Shared with the diagnostics layer, which adds records from whichever thread logs.
+pub struct Output {
+    format: Format,
+    records: Mutex<Vec<Record>>
+}
+
+impl Output {
+    pub fn new(format: Format) -> Arc<Self> {
+        Arc::new(Output { format, records: Mutex::new(vec![]) })
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn emit(&self, record: Record) {
+        match self.format {
+            Format::Text => (),
+            Format::Json => self.records.lock().unwrap().push(record),
+            Format::Ndjson => {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer(&mut stdout, &record).unwrap();
+                writeln!(stdout).unwrap();
+            }
+        }
+    }
+
+    /// Print everything saved up for [`Format::Json`].
+    pub fn finish(&self) {
+        if self.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&*self.records.lock().unwrap()).unwrap());
+        }
+    }
+
+    /// A `tracing` layer that turns solvers' events into diagnostic records, for `trace::init_with`.
+    pub fn diagnostics(self: &Arc<Self>) -> Option<Box<dyn Layer<Registry> + Send + Sync>> {
+        match self.format {
+            Format::Text => None,
+            _ => Some(Box::new(Diagnostics(self.clone()).with_filter(LevelFilter::INFO)))
+        }
+    }
+}
+
+struct Diagnostics(Arc<Output>);
+
+// Each span's fields, kept so that events inside it can include them.
+struct SpanFields(Map<String, Value>);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Diagnostics {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Map::new();
+        attrs.record(&mut JsonFields(&mut fields));
+        ctx.span(id).unwrap().extensions_mut().insert(SpanFields(fields));
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = Map::new();
+        for span in ctx.event_scope(event).into_iter().flat_map(|scope| scope.from_root()) {
+            if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                fields.extend(span_fields.clone());
+            }
+        }
+        event.record(&mut JsonFields(&mut fields));
+        self.0.emit(Record::Diagnostic { level: event.metadata().level().to_string(), fields });
+    }
+}
+
+// Keeps numbers and booleans as JSON numbers and booleans, and everything else as text.
+struct JsonFields<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonFields<'_> {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!["{value:?}"].into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn records_serialize_flat() {
+        let record = Record::answer(11, 2, "sample.txt", "2713310158".to_string(), Duration::from_micros(3));
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"type":"answer","day":11,"part":2,"input":"sample.txt","answer":"2713310158","elapsed_ns":3000}"#
+        );
+    }
+
+    #[test]
+    fn events_become_diagnostics() {
+        let output = Output::new(Format::Json);
+        let subscriber = Registry::default().with(output.diagnostics());
+        tracing::subscriber::with_default(subscriber, || {
+            let _day = tracing::info_span!("day", day = 17).entered();
+            tracing::info!(count = 16u64, elided = 0u64, ready = true, "Checkpoint");
+            tracing::debug!("Too quiet to keep");
+        });
+
+        let records = output.records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let Record::Diagnostic { level, fields } = &records[0] else { panic!("Not a diagnostic") };
+        assert_eq!(level, "INFO");
+        assert_eq!(
+            Value::Object(fields.clone()),
+            serde_json::json!({ "day": 17, "count": 16, "elided": 0, "ready": true, "message": "Checkpoint" })
+        );
+    }
+}
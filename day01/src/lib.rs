@@ -0,0 +1,64 @@
+use std::io::BufRead;
+use utils::error::InputError;
+use utils::groups::{top_sums, Groups, TopK};
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        // Calories (strings representing ints) are separated by new lines;
+        // Groups of calories held by an elf are separated by blank lines.
+        // An `Input` is already all in memory, so this doesn't stream; `main` uses `answers` for that.
+        Groups::new(input.text().as_bytes())
+            .map(|elf| elf.map(|elf| elf.sum))
+            .collect::<Result<_, _>>()
+            .map_err(|err| input.locate(err))
+    }
+
+    // Part 1, find the top elf.
+    fn part1(elf_sums: &Self::Input) -> i32 {
+        top_calories(elf_sums, 1)
+    }
+
+    // Part 2, find the top 3 elves.
+    fn part2(elf_sums: &Self::Input) -> i32 {
+        top_calories(elf_sums, 3)
+    }
+}
+
+/// The total calories carried by the `elves` elves carrying the most.
+pub fn top_calories(elf_sums: &[i32], elves: usize) -> i32 {
+    let mut top = TopK::new(elves);
+    top.extend(elf_sums.iter().copied());
+    top.into_sorted_vec().iter().sum()
+}
+
+/// Both answers read from `reader` a line at a time, keeping only the top 3 elves, so an input of
+/// any size works.
+pub fn answers(reader: impl BufRead) -> Result<(i32, i32), InputError> {
+    let top = top_sums(reader, 3)?;
+    Ok((top.iter().take(1).sum(), top.iter().sum()))
+}
+
+#[test]
+fn top_calories_test() {
+    let elf_sums = Day01::parse(&Input::load("sample.txt").unwrap()).unwrap();
+    assert_eq!(elf_sums, vec![6000, 4000, 11000, 24000, 10000]);
+    assert_eq!(top_calories(&elf_sums, 1), 24000);
+    assert_eq!(top_calories(&elf_sums, 3), 45000);
+    assert_eq!(top_calories(&elf_sums, 10), 55000);
+}
+
+#[test]
+fn answers_test() {
+    let file = std::fs::File::open("sample.txt").unwrap();
+    assert_eq!(answers(std::io::BufReader::new(file)), Ok((24000, 45000)));
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+use utils::load::strategy_parser::{RoundParser, RoundOutcomeParser};
+use utils::rps::*;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    // Each round read both ways: as the hand to play (part 1) and as the outcome to aim for (part 2).
+    type Input = (Vec<(Move, Move)>, Vec<(Move, Outcome)>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let symbols = Symbols::puzzle();
+        let hand_parser = RoundParser::new();
+        let outcome_parser = RoundOutcomeParser::new();
+        Ok((
+            input.parse_lines(|l| hand_parser.parse(&symbols, l))?,
+            input.parse_lines(|l| outcome_parser.parse(&symbols, l))?
+        ))
+    }
+
+    fn part1((hands, _): &Self::Input) -> i32 {
+        let (game, scoring) = (CyclicGame::ROCK_PAPER_SCISSORS, Scoring::puzzle(3));
+        hands.iter()
+            .map(|&(opp, me)| scoring.score(&game, me, opp))
+            .sum()
+    }
+
+    fn part2((_, outcomes): &Self::Input) -> i32 {
+        let (game, scoring) = (CyclicGame::ROCK_PAPER_SCISSORS, Scoring::puzzle(3));
+        outcomes.iter()
+            .map(|&(opp, outcome)| scoring.score(&game, game.play_for_outcome(opp, outcome), opp))
+            .sum()
+    }
+}
+
+/// A way to read my column of the strategy guide: as moves, with each of my symbols meaning the
+/// move paired with it, or as the outcome to aim for, with the symbols for losing, drawing and winning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reading {
+    Moves(Vec<(String, Move)>),
+    Outcomes([String; 3])
+}
+
+impl Display for Reading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reading::Moves(moves) => {
+                let symbols: Vec<&str> = moves.iter().map(|(symbol, _)| symbol.as_str()).collect();
+                let plays: Vec<String> = moves.iter().map(|&(_, m)| format!["{:?}", Play::from_number(m)]).collect();
+                write!(f, "{} as {}", symbols.join(" "), plays.join(" "))
+            },
+            Reading::Outcomes(symbols) => write!(f, "{} as Lose Draw Win", symbols.join(" "))
+        }
+    }
+}
+
+/// What the strategy guide scores under every reading of my column (all 3! ways to read it as
+/// moves, then as outcomes), and the most and least any guide could score against the same
+/// opponent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exploration {
+    pub readings: Vec<(Reading, i32)>,
+    pub best: i32,
+    pub worst: i32
+}
+
+pub fn explore(input: &Input) -> Result<Exploration, InputError> {
+    let (game, scoring, symbols) = (CyclicGame::ROCK_PAPER_SCISSORS, Scoring::puzzle(3), Symbols::puzzle());
+    let parsed = Day02::parse(input)?;
+    let rounds = &parsed.0;
+
+    let mut orderings = permutations(game.moves());
+    orderings.sort();
+    let mut readings: Vec<(Reading, i32)> = orderings.into_iter().map(|moves| {
+        let score = rounds.iter().map(|&(opp, me)| scoring.score(&game, moves[me], opp)).sum();
+        (Reading::Moves(symbols.mine.iter().cloned().zip(moves).collect()), score)
+    }).collect();
+    readings.push((Reading::Outcomes(symbols.outcomes), Day02::part2(&parsed)));
+
+    // Every score I could get in each round, whatever I play.
+    let round_scores: Vec<Vec<i32>> = rounds.iter()
+        .map(|&(opp, _)| (0..game.moves()).map(|me| scoring.score(&game, me, opp)).collect())
+        .collect();
+    Ok(Exploration {
+        readings,
+        best: round_scores.iter().map(|scores| scores.iter().max().unwrap()).sum(),
+        worst: round_scores.iter().map(|scores| scores.iter().min().unwrap()).sum()
+    })
+}
+
+// Every ordering of 0..n.
+fn permutations(n: usize) -> Vec<Vec<Move>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    permutations(n - 1).into_iter().flat_map(|rest| {
+        (0..n).map(move |i| {
+            let mut moves = rest.clone();
+            moves.insert(i, n - 1);
+            moves
+        })
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explore_sample() {
+        let exploration = explore(&Input::load("sample.txt").unwrap()).unwrap();
+        assert_eq!(exploration.readings.len(), 7);
+        let symbols = Symbols::puzzle();
+        let as_moves = Reading::Moves(symbols.mine.iter().cloned().zip([0, 1, 2]).collect());
+        assert!(exploration.readings.contains(&(as_moves.clone(), 15)));
+        assert_eq!(as_moves.to_string(), "X Y Z as Rock Paper Scissors");
+        assert_eq!(exploration.readings.last(), Some(&(Reading::Outcomes(symbols.outcomes), 12)));
+        assert_eq!(exploration.readings[6].0.to_string(), "X Y Z as Lose Draw Win");
+        assert_eq!((exploration.best, exploration.worst), (8 + 9 + 7, 3 + 1 + 2));
+        assert!(exploration.readings.iter().all(|&(_, score)| (6..=24).contains(&score)));
+    }
+
+    #[test]
+    fn every_ordering() {
+        let mut orderings = permutations(3);
+        orderings.sort();
+        assert_eq!(orderings, vec![vec![0, 1, 2], vec![0, 2, 1], vec![1, 0, 2], vec![1, 2, 0], vec![2, 0, 1], vec![2, 1, 0]]);
+    }
+}
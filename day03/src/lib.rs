@@ -0,0 +1,132 @@
+use std::sync::LazyLock;
+use utils::bitset::{Alphabet, BitSet};
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+// Priority
+type Pri = u32;
+
+// Item types in priority order, so an item's number is its priority minus 1.
+static ITEMS: LazyLock<Alphabet> = LazyLock::new(|| Alphabet::new("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+
+// Elves carrying the same badge.
+const GROUP_SIZE: usize = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    // The priority of the item in both of each rucksack's compartments (part 1), and of the badge
+    // each group of elves shares (part 2).
+    type Input = (Vec<Pri>, Vec<Pri>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        // For this one, read a string of lower and upper-case chars.
+        // Convert these into 1-26 (a-z) and 27-52 (A-Z)
+        // Then split the list into two
+        let (common_pris, rucksacks): (Vec<Pri>, Vec<BitSet>) = input.parse_lines(|line| {
+            let [first, second] = load_string_to_two_sets(line)?;
+            common_item(&[first, second]).map(|pri| (pri, first | second))
+        })?.into_iter().unzip();
+
+        // Take groups of three lines. Find the common item type among the three lines.
+        let lines: Vec<&str> = input.lines().collect();
+        let badges = rucksacks.chunks(GROUP_SIZE).enumerate().map(|(group, elves)| {
+            let first_line = group * GROUP_SIZE;
+            match elves.len() {
+                GROUP_SIZE => common_item(elves),
+                n => Err(format!["The last group only has {n} elves, not {GROUP_SIZE}"])
+            }.map_err(|err| InputError::new(err).on_line(first_line + 1, lines[first_line]))
+        }).collect::<Result<_, _>>()?;
+
+        Ok((common_pris, badges))
+    }
+
+    // The sum of priorities of types that are in both compartments.
+    fn part1((common_pris, _): &Self::Input) -> u32 {
+        common_pris.iter().sum()
+    }
+
+    fn part2((_, badges): &Self::Input) -> u32 {
+        badges.iter().sum()
+    }
+}
+
+// The priority of the one item type in all of `sets`.
+fn common_item(sets: &[BitSet]) -> Result<Pri, String> {
+    let common = BitSet::intersect_all(sets.iter().copied());
+    match common.len() {
+        1 => Ok(common.min().unwrap() as Pri + 1),
+        0 => Err(format!["No item type is in all {} of them", sets.len()]),
+        _ => Err(format!["More than one item type is in all {} of them: {}", sets.len(), ITEMS.chars(common)])
+    }
+}
+
+fn load_string_to_two_sets(input: &str) -> Result<[BitSet; 2], String> {
+    // Split into two halves, then turn those into sets of item types.
+    if let Some(c) = input.chars().find(|&c| ITEMS.index(c).is_none()) {
+        return Err(format!["Unexpected item type '{c}'"]);
+    }
+    if !input.len().is_multiple_of(2) {
+        return Err(format!["{} items can't be split between two compartments", input.len()]);
+    }
+    let (first, second) = input.split_at(input.len() / 2);
+    Ok([ITEMS.set(first).unwrap(), ITEMS.set(second).unwrap()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_to_pri(c: char) -> Pri {
+        ITEMS.index(c).unwrap() as Pri + 1
+    }
+
+    #[test]
+    fn convert_char_playground() {
+        assert_eq!('A' as u32, 65);
+        assert_eq!('Z' as u32, 90);
+        assert_eq!('a' as u32, 97);
+        assert_eq!('z' as u32, 122);
+        assert!('a'.is_lowercase());
+        assert!('A'.is_uppercase());
+    }
+
+    #[test]
+    fn char_to_pri_test() {
+        assert_eq!(char_to_pri('a'), 1);
+        assert_eq!(char_to_pri('z'), 26);
+        assert_eq!(char_to_pri('A'), 27);
+        assert_eq!(char_to_pri('Z'), 52);
+    }
+
+    #[test]
+    fn split_test() {
+        assert_eq!(load_string_to_two_sets("AZaz"), Ok([BitSet::from([26, 51]), BitSet::from([0, 25])]));
+        assert!(load_string_to_two_sets("AZa").is_err());
+        assert!(load_string_to_two_sets("AZa1").is_err());
+        assert!(load_string_to_two_sets("AZaé").is_err());
+    }
+
+    #[test]
+    fn intersection_test() {
+        assert_eq!(common_item(&[BitSet::from([1,2,3]), BitSet::from([4,3,2]), BitSet::from([1,2,5])]), Ok(3));
+        assert_eq!(common_item(&[BitSet::from([1,2,3]), BitSet::from([4,5])]), Err("No item type is in all 2 of them".to_string()));
+        assert_eq!(common_item(&[BitSet::from([1,2,3]), BitSet::from([2,3])]), Err("More than one item type is in all 2 of them: cd".to_string()));
+    }
+
+    #[test]
+    fn bad_groups() {
+        let err = |input: &str| Day03::parse(&Input::from(input)).unwrap_err();
+        let bad = err("aa\nbb\ncc");
+        assert_eq!((bad.line, bad.message.as_str()), (Some(1), "No item type is in all 3 of them"));
+        let bad = err("aa\naa\naa\nbcab\nbcdb\ncbeb");
+        assert_eq!((bad.line, bad.message.as_str()), (Some(4), "More than one item type is in all 3 of them: bc"));
+        let bad = err("aa\naa\naa\naa");
+        assert_eq!((bad.line, bad.text.as_deref(), bad.message.as_str()), (Some(4), Some("aa"), "The last group only has 1 elves, not 3"));
+    }
+}
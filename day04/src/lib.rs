@@ -0,0 +1,66 @@
+use utils::ranges::{ClosedRange, IntervalTree};
+use utils::load::range_parser::RangePairParser;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(ClosedRange<u32>, ClosedRange<u32>)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let parser = RangePairParser::new();
+        input.parse_lines(|s| parser.parse(s))
+    }
+
+    // Pairs where one range fully contains the other.
+    fn part1(assign_pairs: &Self::Input) -> u32 {
+        assign_pairs.iter().map(
+            |range_pair| (range_pair.0.fully_contains(&range_pair.1) || range_pair.1.fully_contains(&range_pair.0)) as u32
+        ).sum()
+    }
+
+    // Pairs that overlap.
+    fn part2(assign_pairs: &Self::Input) -> u32 {
+        assign_pairs.iter().map(
+            |range_pair| range_pair.0.overlaps(&range_pair.1) as u32
+        ).sum()
+    }
+}
+
+// Every elf's assignment, across all the pairs.
+fn all_assignments(assign_pairs: &[(ClosedRange<u32>, ClosedRange<u32>)]) -> IntervalTree<u32> {
+    let assignments: Vec<ClosedRange<u32>> = assign_pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+    IntervalTree::new(&assignments)
+}
+
+/// The most elves assigned to any one section.
+pub fn busiest_section(assign_pairs: &[(ClosedRange<u32>, ClosedRange<u32>)]) -> usize {
+    all_assignments(assign_pairs).max_overlap()
+}
+
+/// Elves whose whole assignment some other elf (in any pair) also has. Like part 1, but across pairs.
+pub fn redundant_elves(assign_pairs: &[(ClosedRange<u32>, ClosedRange<u32>)]) -> usize {
+    let tree = all_assignments(assign_pairs);
+    assign_pairs.iter()
+        .flat_map(|&(a, b)| [a, b])
+        .filter(|&assignment| tree.containing(assignment).len() > 1) // Besides its own.
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn across_pairs() {
+        let assign_pairs = Day04::parse(&Input::load("sample.txt").unwrap()).unwrap();
+        assert_eq!(busiest_section(&assign_pairs), 8);
+        assert_eq!(redundant_elves(&assign_pairs), 10);
+    }
+}
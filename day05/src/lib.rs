@@ -0,0 +1,120 @@
+use std::{collections::vec_deque::VecDeque};
+use utils::load::crane_parser::MoveParser;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+type Stacks = Vec<VecDeque<char>>;
+type Move = (u32, u32, u32);
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Stacks, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        // Read each entry in the rows onto the back (push_back) of a vec_deque
+        // Look for a new line
+        // Read each move statement
+        // LALRPOP is going to ignore whitespace, and I haven't figured out how to write custom lexer, so let's
+        // parse the stacks manually.
+        let [stack_block, instructions] = <[_; 2]>::try_from(input.blocks())
+            .map_err(|_| InputError::new("Expected the stacks, a blank line, then the moves"))?;
+        let stacks = parse_stacks(&stack_block.lines);
+
+        let move_parser = MoveParser::new();
+        let moves: Vec<Move> = instructions.parse_lines(|s| move_parser.parse(s))?;
+
+        Ok((stacks, moves))
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> String {
+        let stacks = moves.iter().fold(stacks.clone(), |stacks, next_move| apply_move(stacks, *next_move));
+        stacks.iter().map(|stack| stack.front().unwrap()).collect()
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> String {
+        let stacks = moves.iter().fold(stacks.clone(), |stacks, next_move| apply_move_part2(stacks, *next_move));
+        stacks.iter().map(|stack| stack.front().unwrap()).collect()
+    }
+}
+
+fn parse_stacks(stack_lines: &Vec<&str>) -> Vec<VecDeque<char>> {
+    let mut stacks: Vec<VecDeque<char>> = vec![];
+    // Input lines have their trailing spaces trimmed, so count the stacks from their numbering.
+    let stack_count = stack_lines.last().unwrap().split_whitespace().count();
+    for _ in 0..stack_count {
+        stacks.push(VecDeque::new())
+    }
+
+    for line in stack_lines {
+        let mut stackidx = 0;
+        for crate_name_idx in (1..line.len()).step_by(4) {
+            match line.chars().skip(crate_name_idx).next().unwrap() {
+                ' ' => (),
+                c => stacks[stackidx].push_back(c)
+            }
+            stackidx += 1;
+        }
+    }
+    for s in &mut stacks {
+        s.pop_back(); // Remove the numbering of each stack.
+    }
+    return stacks;
+}
+
+fn apply_move(mut stack: Vec<VecDeque<char>>, next_move: (u32, u32, u32)) -> Vec<VecDeque<char>> {
+    let (mut count, from, to) = next_move;
+    let from = (from - 1) as usize; // Adjust for 1-based indices.
+    let to = (to - 1) as usize;
+
+    while count > 0 {
+        let c = stack[from].pop_front().unwrap();
+        stack[to].push_front(c);
+        count -= 1
+    }
+    return stack;
+}
+
+fn apply_move_part2(mut stack: Vec<VecDeque<char>>, next_move: (u32, u32, u32)) -> Vec<VecDeque<char>> {
+    let (count, from, to) = next_move;
+    let from = (from - 1) as usize; // Adjust for 1-based indices.
+    let to = (to - 1) as usize;
+
+    let mut reverse_stack = vec![];
+    for _ in 0..count {
+        reverse_stack.push(stack[from].pop_front().unwrap());
+    }
+    for c in reverse_stack.iter().rev() {
+        stack[to].push_front(*c)
+    }
+
+    return stack;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_06_test() {
+        let start: Vec<VecDeque<char>> = vec![VecDeque::from(['A', 'B']), VecDeque::from(['C'])];
+        let actual = apply_move(start, (1, 1, 2)); // Move 1 from 1 to 2, moves A from the first to the second.
+        assert_eq!(actual,  vec![VecDeque::from(['B']), VecDeque::from(['A', 'C'])]);
+
+        let start: Vec<VecDeque<char>> = vec![VecDeque::from(['A', 'B', 'C']), VecDeque::from(['D', 'E'])];
+        let actual = apply_move(start, (2, 1, 2)); // Move 1 from 1 to 2, moves A from the first to the second.
+        assert_eq!(actual,  vec![VecDeque::from(['C']), VecDeque::from(['B', 'A', 'D', 'E'])]);
+    }
+
+    #[test]
+    fn part_2_test() {
+        let start: Vec<VecDeque<char>> = vec![VecDeque::from(['A', 'B', 'C']), VecDeque::from(['D'])];
+        let actual = apply_move_part2(start, (2, 1, 2)); // Move 1 from 1 to 2, moves A from the first to the second.
+        assert_eq!(actual,  vec![VecDeque::from(['C']), VecDeque::from(['A', 'B', 'D'])]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        Ok(input.text().to_string())
+    }
+
+    // First start-of-packet marker.
+    fn part1(input: &Self::Input) -> usize {
+        find_first_unique_4(input)
+    }
+
+    // First start-of-message marker.
+    fn part2(input: &Self::Input) -> usize {
+        find_first_unique_N(input, 14)
+    }
+}
+
+fn find_first_unique_4(s: &str) -> usize {
+    find_first_unique_N(&s, 4)
+}
+
+fn find_first_unique_N(s: &str, n: usize) -> usize {
+    let cvec = s.chars().collect::<Vec<char>>();
+    let mut window_sets = cvec.windows(n).map(
+        |w| w.iter().copied().collect::<HashSet<char>>()
+    );
+    window_sets.find_position(
+        |set| set.len() == n
+    ).unwrap().0 + n // add N to the position since it's the position of the last character.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_06_part1_test() {
+        assert_eq!(find_first_unique_4("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(find_first_unique_4("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(find_first_unique_4("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(find_first_unique_4("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+    }
+
+    #[test]
+    fn day_06_part2_test() {
+        assert_eq!(find_first_unique_N("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), 19);
+        assert_eq!(find_first_unique_N("bvwbjplbgvbhsrlpgdmjqwftvncz", 14), 23);
+        assert_eq!(find_first_unique_N("nppdvjthqldpwncqszvftbrmjlhg", 14), 23);
+        assert_eq!(find_first_unique_N("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14), 29);
+        assert_eq!(find_first_unique_N("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14), 26);
+    }
+}
\ No newline at end of file
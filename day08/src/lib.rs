@@ -0,0 +1,61 @@
+use utils::error::InputError;
+use utils::grid2::{Grid2, Pos, DELTAS4};
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid2<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        input.grid(
+            |c| c.to_digit(10).map(|d| d as u8).ok_or(format!["Expected a tree height, found '{c}'"])
+        )
+    }
+
+    // The number of visible trees.
+    fn part1(field: &Self::Input) -> u32 {
+        field.positions().filter(|&pos| visible(field, pos)).count() as u32
+    }
+
+    // The max viewing distance.
+    fn part2(field: &Self::Input) -> u32 {
+        field.positions().map(|pos| viewing_distance(field, pos)).max().unwrap()
+    }
+}
+
+fn visible(field: &Grid2<u8>, pos: Pos) -> bool {
+    // Visible if every tree in some direction is shorter, all the way to the edge.
+    // Trees on the edge have nothing in the way, so they're always visible.
+    let height = field[pos];
+    DELTAS4.iter().any(|&dir| field.ray(pos, dir).all(|tree| field[tree] < height))
+}
+
+fn viewing_distance(field: &Grid2<u8>, pos: Pos) -> u32 {
+    let height = field[pos];
+
+    // In each direction, count the trees up to and including the first one at least as tall.
+    DELTAS4.iter().map(|&dir| {
+        let mut view = 0;
+        for tree in field.ray(pos, dir) {
+            view += 1;
+            if field[tree] >= height { break; }
+        }
+        view
+    }).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_08_part1_test() {
+
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+mod processor;
+pub use processor::{Processor, Instruction};
+
+use utils::error::InputError;
+use utils::grid2::Grid2;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        input.parse_lines(|s| s.parse::<Instruction>())
+    }
+
+    // Sum of signal strengths on the 20th, 60th, ... 220th ticks.
+    fn part1(instructions: &Self::Input) -> i32 {
+        let mut processor = Processor::new(instructions.clone());
+
+        for _ in 0..19 { // The prompt is to get X *during* the tick, not *after* the tick.
+            processor.tick();
+        }
+        let mut signal_strengths = processor.signal_strength();
+
+        for _ in 0..5 {
+            for _ in 0..40 {
+                processor.tick();
+            }
+            signal_strengths += processor.signal_strength();
+        }
+
+        signal_strengths
+    }
+
+    // The letters drawn on the CRT, one line per row of the display.
+    fn part2(instructions: &Self::Input) -> String {
+        let mut display: Grid2<char> = Grid2::new(6, 40, '.');
+        let mut processor = Processor::new(instructions.clone());
+
+        while processor.clock <= 240 {
+            let cycle = processor.clock;
+            let pixel = cycle - 1;
+            let pixel_row = ((pixel / 40) % 6) as usize;
+            let pixel_col = (pixel % 40) as usize;
+            let sprite_rg = (processor.x-1)..=(processor.x+1);
+
+            if sprite_rg.contains(&(pixel_col as i32)) {
+                display[pixel_row][pixel_col] = '#'
+            } else {
+                display[pixel_row][pixel_col] = '.'
+            }
+            processor.tick();
+        }
+
+        display.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_10_part1_test() {
+        assert_eq![1, 1]
+    }
+}
\ No newline at end of file
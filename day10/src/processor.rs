@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Instruction {
   Add(i32),
   Noop
@@ -13,7 +13,7 @@ impl FromStr for Instruction {
     let tokens: Vec<&str> = input.split(" ").collect();
     match tokens[0] {
       "noop" => Ok(Instruction::Noop),
-      "addx" => Ok(Instruction::Add(tokens[1].parse::<i32>().unwrap())),
+      "addx" => tokens.get(1).and_then(|v| v.parse::<i32>().ok()).map(Instruction::Add).ok_or(()),
       _ => Err(())
     }
   }
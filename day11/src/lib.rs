@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+use tracing::{debug, info};
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        input.blocks().iter().map(
+            |block| load_monkey(&block.lines).map_err(|err| block.locate(err))
+        ).collect()
+    }
+
+    fn part1(monkeys: &Self::Input) -> u64 {
+        play_rounds(monkeys, 20, 3 /* relief */)
+    }
+
+    fn part2(monkeys: &Self::Input) -> u64 {
+        play_rounds(monkeys, 10000, 1 /* relief */)
+    }
+}
+
+fn play_rounds(monkeys: &[Monkey], rounds: u32, relief: u64) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    for m in &monkeys {
+        debug!(?m, "Starting monkey");
+    }
+
+    for round in 1..=rounds {
+        play_round(&mut monkeys, relief);
+        for (i, m) in monkeys.iter().enumerate() {
+            debug!(round, monkey = i, items = ?m.items, inspected = m.inspect_count);
+        }
+    }
+
+    for (i, m) in monkeys.iter().enumerate() {
+        info!(monkey = i, inspected = m.inspect_count, "After {rounds} rounds");
+    }
+    monkey_business(&monkeys)
+}
+
+fn monkey_business(monkeys: &Vec<Monkey>) -> u64 {
+    let mut monkey_business: Vec<u64> = monkeys.iter().map(|m| m.inspect_count).collect();
+    monkey_business.sort();
+    monkey_business.reverse();
+    monkey_business[0] * monkey_business[1]
+}
+
+fn load_monkey(lines: &[&str]) -> Result<Monkey, InputError> {
+    let items: VecDeque<u64> = parse_field(lines, 1, "Starting items: ",
+        |s| s.split(", ").map(|s| s.parse::<u64>()).collect()
+    )?;
+    let operation: Vec<Op> = parse_field(lines, 2, " = ", |s| match Op::parse(s.split(" ").collect())? {
+        ops if ops.len() == 3 => Ok(ops),
+        _ => Err("Expected an operation like \"old * 19\"")
+    })?;
+    let divisor = parse_field(lines, 3, " by ", |s| s.parse::<u64>())?;
+    let monkey_idx_if_true = parse_field(lines, 4, " monkey ", |s| s.parse::<usize>())?;
+    let monkey_idx_if_false = parse_field(lines, 5, " monkey ", |s| s.parse::<usize>())?;
+    Ok(Monkey { items, operation, divisor, monkey_idx_if_true, monkey_idx_if_false, inspect_count: 0 })
+}
+
+// Parse the text after `label` on line `i` of a monkey's block, e.g. the "19" in "  Test: divisible by 19".
+fn parse_field<T, E: Into<InputError>>(lines: &[&str], i: usize, label: &str, parse: impl FnOnce(&str) -> Result<T, E>) -> Result<T, InputError> {
+    let line = lines.get(i).copied().unwrap_or_default();
+    let value = match line.split_once(label) {
+        Some((_, value)) => parse(value).map_err(Into::into),
+        None => Err(InputError::new(format!["Expected \"{}\"", label.trim()]))
+    };
+    value.map_err(|err| err.on_line(i + 1, line))
+}
+
+fn play_round(monkeys: &mut Vec<Monkey>, relief: u64) {
+    let common_divisor = monkeys.iter().map(|m| &m.divisor).product::<u64>();
+
+    for i in 0..monkeys.len() {
+        while let Some(item) = monkeys[i].items.pop_front() {
+            // Inspect an item -- relief makes value be floor-divided by relief level.
+            monkeys[i].inspect_count += 1;
+            let worry_level = calc_new_worry(item, &monkeys[i].operation) / &relief;
+            let worry_level = worry_level % &common_divisor;
+
+            // Monkey tests worry level
+            if &worry_level % &monkeys[i].divisor == 0 {
+                let next_monkey = monkeys[i].monkey_idx_if_true;
+                monkeys[next_monkey].items.push_back(worry_level);
+            } else {
+                let next_monkey = monkeys[i].monkey_idx_if_false;
+                monkeys[next_monkey].items.push_back(worry_level);
+            }
+        }
+    }
+}
+
+fn calc_new_worry(old: u64, ops: &Vec<Op>) -> u64 {
+    let l = match &ops[0] {
+        Op::Old => &old,
+        Op::Num(x) => x,
+        _ => panic!("Invalid left")
+    };
+    let r = match &ops[2] {
+        Op::Old => &old,
+        Op::Num(x) => x,
+        _ => panic!("Invalid right")
+    };
+
+    match ops[1] {
+        Op::Plus => l + r,
+        Op::Times => l * r,
+        _ => panic!("Invalid expression")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Op {
+    Old,
+    Times,
+    Plus,
+    Num(u64)
+}
+
+impl Op {
+    fn parse(oplist: Vec<&str>) -> Result<Vec<Op>, &'static str> {
+        oplist.iter().map(Self::parse_one).collect()
+    }
+
+    fn parse_one(op: &&str) -> Result<Op, &'static str> {
+        match *op {
+            "old" => Ok(Op::Old),
+            "*" => Ok(Op::Times),
+            "+" => Ok(Op::Plus),
+            x if x.parse::<u64>().is_ok() => Ok(Op::Num(x.parse::<u64>().unwrap())),
+            _ => Err("Invalid op!")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Vec<Op>,
+    divisor: u64,
+    monkey_idx_if_true: usize,
+    monkey_idx_if_false: usize,
+    inspect_count: u64
+}
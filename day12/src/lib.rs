@@ -0,0 +1,82 @@
+use std::vec;
+
+use utils::error::InputError;
+use utils::grid2::Grid2;
+use utils::input::Input;
+use utils::search::{bfs, bfs_multi, Graph};
+use utils::solution::Solution;
+
+type Pt = (usize, usize);
+
+pub struct Map {
+    terrain: Grid2<i32>,
+    start: Pt,
+    end: Pt,
+    all_as: Vec<Pt>
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Map;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let squares = input.grid(|chr| match chr {
+            'S' | 'E' | 'a'..='z' => Ok(chr),
+            c => Err(format!["Unexpected square '{c}'"])
+        })?;
+        let mut terrain: Grid2<i32> = Grid2::new(squares.rows(), squares.cols(), 0);
+        let mut start: Option<Pt> = None;
+        let mut end: Option<Pt> = None;
+        let mut all_as: Vec<Pt> = vec![];
+
+        for (row, col) in squares.positions() {
+            terrain[(row, col)] = match squares[(row, col)] {
+                'S' => {
+                    start = Some((row, col));
+                    all_as.push((row, col));
+                    'a' as i32
+                },
+                'E' => {
+                    end = Some((row, col));
+                    'z' as i32
+                },
+                'a' => {
+                    all_as.push((row, col));
+                    'a' as i32
+                }
+                c => c as i32
+            }
+        }
+
+        let start = start.ok_or(InputError::new("No start square (S)"))?;
+        let end = end.ok_or(InputError::new("No end square (E)"))?;
+        Ok(Map { terrain, start, end, all_as })
+    }
+
+    // The fewest steps from S to E.
+    fn part1(map: &Self::Input) -> u32 {
+        bfs(&Climb(&map.terrain), map.start).distance(&map.end).unwrap() as u32
+    }
+
+    // The fewest steps to E from any a.
+    fn part2(map: &Self::Input) -> u32 {
+        bfs_multi(&Climb(&map.terrain), map.all_as.clone()).distance(&map.end).unwrap() as u32
+    }
+}
+
+// Moves between squares on the terrain: at most one higher, or any amount lower.
+struct Climb<'a>(&'a Grid2<i32>);
+
+impl Graph for Climb<'_> {
+    type Node = Pt;
+
+    fn neighbors(&self, &pt: &Pt) -> Vec<Pt> {
+        let terrain = self.0;
+        terrain.neighbors4(pt).filter(|&next| terrain[next] <= terrain[pt] + 1).collect()
+    }
+}
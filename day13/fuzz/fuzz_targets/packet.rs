@@ -0,0 +1,13 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use day13::lists_parser::PacketParser;
+
+static PARSER: LazyLock<PacketParser> = LazyLock::new(PacketParser::new);
+
+// Whatever parses prints back to something that parses the same.
+fuzz_target!(|line: &str| {
+    if let Ok(packet) = PARSER.parse(line) {
+        assert_eq!(PARSER.parse(&packet.to_string()), Ok(packet));
+    }
+});
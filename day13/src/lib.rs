@@ -0,0 +1,105 @@
+pub mod lists;
+use lists::LorV;
+
+use lalrpop_util::*;
+lalrpop_mod!(pub lists_parser);
+
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(LorV, LorV)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let parser = lists_parser::PacketParser::new();
+        input.blocks()
+            .iter()
+            .map(
+                |block| {
+                    let pair = block.parse_lines(|line| parser.parse(line))?;
+                    return match <[LorV; 2]>::try_from(pair) {
+                        Ok([left, right]) => Ok((left, right)),
+                        Err(_) => Err(InputError::new("Expected a pair of packets").on_line(block.first_line, block.lines[0]))
+                    };
+                }
+            ).collect()
+    }
+
+    // The sum of indices of pairs in the right order.
+    fn part1(pairs: &Self::Input) -> usize {
+        let indices: Vec<usize> = pairs.iter().enumerate().filter_map(|(i, pair)| if pair.0 <= pair.1 { Some(i+1) } else { None }).collect();
+        indices.iter().sum()
+    }
+
+    // The decoder key.
+    fn part2(pairs: &Self::Input) -> usize {
+        let mut packets: Vec<LorV> = pairs.iter().flat_map(|(l, r)| [l.clone(), r.clone()]).collect();
+        let divider_2 = LorV::L(vec![LorV::L(vec![LorV::V(2)])]);
+        let divider_6 = LorV::L(vec![LorV::L(vec![LorV::V(6)])]);
+        packets.push(divider_2.clone());
+        packets.push(divider_6.clone());
+        packets.sort();
+
+        let divider_2_idx = packets.iter().enumerate().find(|(_, p)| **p == divider_2).unwrap().0 + 1; // +1 for 1-based indexing.
+        let divider_6_idx = packets.iter().enumerate().find(|(_, p)| **p == divider_6).unwrap().0 + 1;
+        divider_2_idx * divider_6_idx
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn parser_tests() {
+        let parser = lists_parser::PacketParser::new();
+        assert_eq![format!["{:?}", parser.parse("[1,1,3,1,1]").unwrap()], "L([V(1), V(1), V(3), V(1), V(1)])"];
+        assert_eq![format!["{:?}", parser.parse("[[4,4],4,4,4]").unwrap()], "L([L([V(4), V(4)]), V(4), V(4), V(4)])"];
+        assert_eq![format!["{:?}", parser.parse("[]").unwrap()], "L([])"];
+        assert_eq![format!["{:?}", parser.parse("[[]]").unwrap()], "L([L([])])"];
+    }
+
+    fn packet() -> impl Strategy<Value = LorV> {
+        any::<i32>().prop_map(LorV::V).prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(LorV::L)
+        })
+    }
+
+    // Printing a packet and parsing it gives back the same packet, including empty lists, which the
+    // grammar has its own "[]" token for.
+    #[test]
+    fn parser_round_trip() {
+        let parser = lists_parser::PacketParser::new();
+        proptest!(|(packet in packet())| {
+            let line = packet.to_string();
+            prop_assert_eq!(parser.parse(&line), Ok(packet));
+        });
+        proptest!(|(big in i32::MAX as i64 + 1..=i64::MAX)| {
+            let line = format!["[1,[{big}]]"];
+            let unparsable = matches!(parser.parse(&line), Err(ParseError::User { .. }));
+            prop_assert!(unparsable);
+        });
+        proptest!(|(line in "[\\[\\],0-9 -]{0,30}")| {
+            let _ = parser.parse(&line);
+        });
+    }
+
+    #[test]
+    fn list_tests() {
+        let parser = lists_parser::PacketParser::new();
+        assert![parser.parse("[]").unwrap() <= parser.parse("[3]").unwrap()];
+        assert![parser.parse("[[1],[2,3,4]]").unwrap() <= parser.parse("[[1],4]").unwrap()];
+        assert![!(parser.parse("[7,7,7,7]").unwrap() <= parser.parse("[7,7,7]").unwrap())];
+        assert![!(parser.parse("[[[]]]").unwrap() <= parser.parse("[[]]").unwrap())];
+    }
+}
\ No newline at end of file
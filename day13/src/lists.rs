@@ -20,6 +20,16 @@ impl Ord for LorV {
   }
 }
 
+// Prints a packet the way the puzzle writes it, e.g. `[[1],[2,3,4]]`.
+impl std::fmt::Display for LorV {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      V(v) => write!(f, "{v}"),
+      L(list) => write!(f, "[{}]", list.iter().map(LorV::to_string).collect::<Vec<_>>().join(","))
+    }
+  }
+}
+
 impl PartialOrd for LorV {
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
       Some(self.cmp(other))
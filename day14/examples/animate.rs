@@ -0,0 +1,10 @@
+use day14::{pour_sand, Day14};
+use utils::render::animate_from_args;
+use utils::solution::Solution;
+
+fn main() {
+    animate_from_args(|input, part, recorder| {
+        pour_sand(&Day14::parse(input)?, part, Some(recorder));
+        Ok(())
+    });
+}
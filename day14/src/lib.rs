@@ -0,0 +1,182 @@
+use std::cmp::{min,max};
+use std::collections::HashSet;
+
+use utils::load::coord_2d_parser::PolyParser;
+use utils::coordinates::Coord;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::render::{Cell, Frame, Recorder};
+use utils::solution::{Part, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Vec<Coord>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let parser = PolyParser::new();
+        input.parse_lines(|text_line| parser.parse(text_line))
+    }
+
+    // Number of sand units that come to rest.
+    fn part1(polys: &Self::Input) -> u32 {
+        pour_sand(polys, Part::One, None)
+    }
+
+    // Number of sand units that come to rest on the floor.
+    fn part2(polys: &Self::Input) -> u32 {
+        pour_sand(polys, Part::Two, None)
+    }
+}
+
+/// Drop sand until it falls into the abyss (part 1) or blocks the source (part 2), recording the cave
+/// after each unit comes to rest. Returns the number of units that came to rest.
+pub fn pour_sand(polys: &Vec<Vec<Coord>>, part: Part, mut recorder: Option<&mut Recorder>) -> u32 {
+    let bottom: i32 = polys.iter().flatten().map(|c| c.y).max().unwrap();
+    let rocks = get_walls(polys);
+    let mut walls = rocks.clone();
+    let drop_one = if part == Part::One { drop_one_sand } else { drop_one_sand_part2 };
+    let mut num_sands = 0;
+    while drop_one(&mut walls, bottom) {
+        num_sands += 1;
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.record(&Cave::new(&rocks, &walls, bottom, part));
+        }
+    }
+    num_sands
+}
+
+// The part of the cave sand can reach: in part 1, just past the rocks on either side, and in part 2,
+// everything above the floor that sand piling up from the source could cover.
+struct Cave<'a> {
+    rocks: &'a HashSet<Coord>,
+    walls: &'a HashSet<Coord>,
+    min_x: i32,
+    cols: usize,
+    rows: usize,
+    floor: Option<i32>
+}
+
+impl<'a> Cave<'a> {
+    fn new(rocks: &'a HashSet<Coord>, walls: &'a HashSet<Coord>, bottom: i32, part: Part) -> Self {
+        let (min_x, max_x, floor) = match part {
+            Part::One => (rocks.iter().map(|c| c.x).min().unwrap() - 1, rocks.iter().map(|c| c.x).max().unwrap() + 1, None),
+            Part::Two => (500 - bottom - 2, 500 + bottom + 2, Some(bottom + 2))
+        };
+        let rows = floor.unwrap_or(bottom) as usize + 1;
+        Cave { rocks, walls, min_x, cols: (max_x - min_x + 1) as usize, rows, floor }
+    }
+}
+
+const ROCK: Cell = Cell::new('#', [120, 110, 100]);
+const SAND: Cell = Cell::new('o', [230, 190, 90]);
+const AIR: Cell = Cell::new('.', [20, 20, 30]);
+
+impl Frame for Cave<'_> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Cell {
+        let pos = Coord::new2(self.min_x + col as i32, row as i32);
+        if self.rocks.contains(&pos) || self.floor == Some(pos.y) {
+            ROCK
+        } else if self.walls.contains(&pos) {
+            SAND
+        } else {
+            AIR
+        }
+    }
+}
+
+fn get_walls(polys: &Vec<Vec<Coord>>) -> HashSet<Coord> {
+    let mut walls: HashSet<Coord> = HashSet::new();
+
+    for poly in polys {
+        for line in poly.windows(2) {
+            let start = line[0];
+            let end = line[1];
+            if start.x == end.x {
+                // Add every point in between start and end varying y
+                for y in min(start.y, end.y)..=max(start.y, end.y) {
+                    walls.insert(Coord::new2(start.x, y));
+                }
+            } else if start.y == end.y {
+                // Add every point in between start and end varying x
+                for x in min(start.x, end.x)..=max(start.x, end.x) {
+                    walls.insert(Coord::new2(x, start.y));
+                }
+            } else {
+                panic!("No diagonals!");
+            }
+        }
+    }
+
+    return walls;
+}
+
+// Return true if the sand stops, false if it fell forever.
+fn drop_one_sand(walls: &mut HashSet<Coord>, bottom: i32) -> bool {
+    let mut sand = Coord::new2(500,0);
+
+    'outer: while sand.y <= bottom {
+        let dirs = [
+            Coord::new2(sand.x, sand.y+1),   // down
+            Coord::new2(sand.x-1, sand.y+1), // down, left
+            Coord::new2(sand.x+1, sand.y+1)  // down, right
+        ];
+        for direction in dirs {
+            if walls.contains(&direction) {
+                continue; // Try the next direction.
+            } else {
+                sand = direction; // This direction isn't blocked; go that way.
+                continue 'outer;
+            }
+        }
+
+        // Sand was fully blocked and comes to rest.
+        walls.insert(sand);
+        return true;
+    }
+
+    // Sand passed the bottom-most coordinate, so it will fall forever.
+    return false;
+}
+
+// Return true if the sand fell; false if the last drop blocked the source.
+fn drop_one_sand_part2(walls: &mut HashSet<Coord>, bottom: i32) -> bool {
+    let mut sand = Coord::new2(500,0);
+
+    if walls.contains(&sand) {
+        return false;
+    }
+
+    'outer: while sand.y <= bottom {
+        let dirs = [
+            Coord::new2(sand.x, sand.y+1),   // down
+            Coord::new2(sand.x-1, sand.y+1), // down, left
+            Coord::new2(sand.x+1, sand.y+1)  // down, right
+        ];
+        for direction in dirs {
+            if walls.contains(&direction) {
+                continue; // Try the next direction.
+            } else {
+                sand = direction; // This direction isn't blocked; go that way.
+                continue 'outer;
+            }
+        }
+        break;
+    }
+
+    // We hit the bottom. Don't bother looking farther down.
+    walls.insert(sand);
+    return true;
+}
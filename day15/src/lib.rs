@@ -0,0 +1,101 @@
+#[macro_use] extern crate scan_fmt;
+use utils::coordinates::{Coord, Line};
+use utils::ranges::{ClosedRange, RangeSet};
+use utils::parallel;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+use std::cmp::{min, max};
+use itertools::Iterate;
+
+const TUNING_FREQUENCY: i32 = 4_000_000;
+
+pub struct Sensors {
+    sensor_beacons: Vec<Line>,
+    y: i32,
+    max_beacon: i32
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Sensors;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let sensor_beacons: Vec<Line> = input.parse_lines(
+            |line| {
+                let (sx, sy, bx, by) = scan_fmt!(line, "Sensor at x={}, y={}: closest beacon is at x={}, y={}", i32, i32, i32, i32)?;
+                Ok::<_, InputError>(Line::new(Coord::new2(sx, sy), Coord::new2(bx, by)))
+            }
+        )?;
+
+        // The sample asks about a different row and search area than the puzzle input does.
+        let is_sample = input.name().is_some_and(|name| name.contains("sample.txt"));
+        let y = if is_sample { 10 } else { 2000000 };
+        let max_beacon: i32 = if is_sample { 20 } else { TUNING_FREQUENCY };
+
+        Ok(Sensors { sensor_beacons, y, max_beacon })
+    }
+
+    // Positions where no beacon can be.
+    fn part1(sensors: &Self::Input) -> i32 {
+        let covered = ranges_for_y(&sensors.sensor_beacons, sensors.y);
+        let mut beacons: Vec<i32> = sensors.sensor_beacons.iter().filter(|sb| sb.e.y == sensors.y).map(|sb| sb.e.x).collect();
+        beacons.sort();
+        beacons.dedup();
+        covered.covered() - beacons.len() as i32
+    }
+
+    // The tuning frequency.
+    fn part2(sensors: &Self::Input) -> i64 {
+        let search_area = ClosedRange::new(0, sensors.max_beacon);
+        let (uncovered, y) = parallel::find_map_first(0..sensors.max_beacon as usize + 1, |y| {
+            let uncovered = ranges_for_y(&sensors.sensor_beacons, y as i32).complement(search_area);
+            (!uncovered.is_empty()).then_some((uncovered, y))
+        }).unwrap();
+
+        let x = uncovered.ranges()[0].start;
+        (x as i64 * TUNING_FREQUENCY as i64) + y as i64
+        // Tried 2014772348, but that's too low.
+        // I needed to use larger ints to calculate tuning!
+    }
+}
+
+// All the x-values covered by any sensor for a given y-value
+fn ranges_for_y(sensor_beacons: &Vec<Line>, y: i32) -> RangeSet<i32> {
+    sensor_beacons.iter().filter_map(|sb| range_for_y(sb, y)).collect()
+}
+
+// Gets the range of x-values that are covered by a sensor for a given y-value
+fn range_for_y(sensor_beacon: &Line, y: i32) -> Option<ClosedRange<i32>> {
+    let dist = sensor_beacon.manhattan();
+    let rem = dist - (sensor_beacon.s.y - y).abs();
+    if rem < 0 {
+        return None;
+    }
+
+    return Some(ClosedRange::new(sensor_beacon.s.x - rem, sensor_beacon.s.x + rem));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_15_test() {
+        assert_eq!(range_for_y(&Line::new(Coord::new2(1, 1), Coord::new2(2, 2)), -2), None);
+        assert_eq!(range_for_y(&Line::new(Coord::new2(1, 1), Coord::new2(2, 2)), -1), Some(ClosedRange::new(1,1)));
+        assert_eq!(range_for_y(&Line::new(Coord::new2(1, 1), Coord::new2(2, 2)), 0), Some(ClosedRange::new(0,2)));
+    }
+
+    #[test]
+    fn sample_by_name() {
+        let sensors = Day15::parse(&Input::load("sample.txt").unwrap()).unwrap();
+        assert_eq!(Day15::part1(&sensors), 26);
+        assert_eq!(Day15::part2(&sensors), 56000011);
+    }
+}
\ No newline at end of file
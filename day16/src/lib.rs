@@ -0,0 +1,780 @@
+#[macro_use] extern crate scan_fmt;
+use core::time;
+use std::{collections::{HashMap, HashSet}, fmt::Debug};
+use std::sync::atomic::{AtomicU32, Ordering};
+use utils::error::InputError;
+use utils::input::Input;
+use utils::generate::{Generate, Param, Params, Rng};
+use utils::memo::Memo;
+use utils::solution::Solution;
+use utils::validate::{Assumption, Validate};
+use utils::search::bfs;
+use utils::parallel;
+use tracing::debug;
+
+type Graph = HashMap<Valve, Vec<Valve>>;
+type Release = u16;
+type Distance = u32;
+type Distances = HashMap<Valve, Distance>;
+type DistMatrix = HashMap<Valve, Distances>;
+type VisitList = HashSet<Valve>;
+
+#[derive(Clone)]
+pub struct Valve {
+    id: [char; 2],
+    release: Release
+}
+
+impl std::cmp::PartialOrd for Valve {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for Valve {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl std::cmp::PartialEq<Valve> for Valve {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl std::cmp::Eq for Valve {}
+
+impl std::hash::Hash for Valve {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Valve {
+    fn from(label: &str, release: Release) -> Self {
+        let mut iter = label.chars();
+        Self {
+            id: [iter.next().unwrap(), iter.next().unwrap()],
+            release
+        }
+    }
+
+    fn from_id(label: &str) -> Self {
+        Self::from(label, 0)
+    }
+}
+
+impl Debug for Valve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut iditer = self.id.iter();
+        write!(f, "<{}{}_{}>", iditer.next().unwrap(), iditer.next().unwrap(), self.release)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = (DistMatrix, VisitList);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        parse_input(input)
+    }
+
+    // Max pressure release.
+    fn part1((distances, positive_valves): &Self::Input) -> u32 {
+        day16_p1(distances, positive_valves)
+    }
+
+    // Max pressure release with an elephant helper.
+    fn part2((distances, positive_valves): &Self::Input) -> u32 {
+        day16_p2(distances, positive_valves)
+    }
+}
+
+#[cfg(test)]
+fn parse(path: &str) -> (DistMatrix, VisitList) {
+    parse_input(&Input::load(path).unwrap()).unwrap()
+}
+
+fn parse_input(input: &Input) -> Result<(DistMatrix, VisitList), InputError> {
+    // Parse input
+    let instructions: Vec<(String, Release, String)> = input.parse_lines(
+        |line| scan_fmt![line, "Valve {} has flow rate={d}; {*/tunnels lead|tunnel leads/} to valve{*/s?/} {/.*/}", String, Release, String]
+    )?;
+
+    let valves: VisitList = instructions.iter().map(|ins| Valve::from(&ins.0, ins.1)).collect();
+    let tunnels: Graph = instructions.iter()
+        .map(
+            |ins| (Valve::from_id(&ins.0), ins.2.split(", ").map(|s| Valve::from_id(s)).collect())
+        ).collect();
+
+    let distances = all_distances(&tunnels, &valves);
+    let positive_valves: VisitList = valves.iter().filter(|&v| v.release > 0).cloned().collect();
+
+    return Ok((distances, positive_valves));
+}
+
+fn day16_p1(distances: &DistMatrix, positive_valves: &VisitList) -> u32 {
+    // Part 1
+    // Make a list of all of the valves that aren't 0-flow-rate.
+    // Starting at AA, find the next-maximal valve to go turn on, accounting for time to walk there and turn it on.
+    // Keep finding the next-maximal valve.
+    let start: Valve = Valve::from_id("AA");
+    let minutes_remain: u32 = 30;
+
+    debug!(?positive_valves);
+
+    // Each first valve to open (AA itself, if it releases pressure, with no walk) is its own search,
+    // so they can run in parallel. They share the best release seen so far to prune with, as far as
+    // it's known when each one starts.
+    let best = AtomicU32::new(0);
+    let firsts: Vec<&Valve> = positive_valves.iter().collect();
+    let releases = parallel::map(&firsts, |&first| {
+        let mut best_seen = best.load(Ordering::Relaxed);
+        let mut seen = Seen::new(positive_valves);
+        let pressure_released = find_max_release(
+            first,
+            distances,
+            positive_valves.clone(),
+            0 /* curr_flow */,
+            distances[&start][first] /* walk_remain */,
+            minutes_remain,
+            0 /* accumulation */,
+            &mut best_seen,
+            &mut seen
+        );
+        debug!(?first, "{}", seen.memo.stats());
+        best.fetch_max(best_seen, Ordering::Relaxed);
+        pressure_released
+    });
+
+    let pressure_released = releases.into_iter().max().unwrap_or(0);
+    if best.into_inner() != pressure_released {
+        panic!("Not supposed to do that.");
+    }
+
+    return pressure_released;
+}
+
+fn day16_p2(distances: &DistMatrix, positive_valves: &VisitList) -> u32 {
+    // Part 2
+    let start: Valve = Valve::from_id("AA");
+    let minutes_remain: u32 = 26;
+
+    debug!(?positive_valves);
+
+    // As in part 1, each pair of first valves (one for me and one for the elephant) is its own search.
+    let best = AtomicU32::new(0);
+    let firsts: Vec<(&Valve, &Valve)> = positive_valves.iter()
+        .flat_map(|dest| positive_valves.iter().map(move |dest_el| (dest, dest_el)))
+        .filter(|(dest, dest_el)| dest != dest_el || positive_valves.len() == 1)
+        .collect();
+    let releases = parallel::map(&firsts, |&(first, first_el)| {
+        let mut best_seen = best.load(Ordering::Relaxed);
+        let pressure_released = find_max_release_p2(
+            first,
+            first_el,
+            distances,
+            positive_valves.clone(),
+            0 /* curr_flow */,
+            distances[&start][first] /* walk_remain */,
+            distances[&start][first_el] /* walk_reamin_el */,
+            minutes_remain,
+            0 /* accumulation */,
+            &mut best_seen
+        );
+        best.fetch_max(best_seen, Ordering::Relaxed);
+        pressure_released
+    });
+
+    // Expected shortest walk for sample:
+    // You --      AA ->    JJ -> BB -> CC
+    // Elephant -- AA -> DD    -> HH ->    EE
+
+    let pressure_released = releases.into_iter().max().unwrap_or(0);
+    if best.into_inner() != pressure_released {
+        panic!("Not supposed to do that.");
+    }
+
+    return pressure_released;
+}
+
+// Everything the rest of a part 1 search depends on besides what's been released so far, with the
+// valves still closed as a bitmask.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct State {
+    at: [char; 2],
+    closed: u64,
+    curr_flow: u32,
+    walk_remain: u32,
+    time_remain: u32
+}
+
+// The most pressure released on the way to each state. What's left to release from a state doesn't
+// depend on how it got there, so getting there again with no more released can't do any better.
+struct Seen {
+    bits: HashMap<Valve, u64>,
+    memo: Memo<State, u32>
+}
+
+impl Seen {
+    fn new(positive_valves: &VisitList) -> Self {
+        assert!(positive_valves.len() <= 64, "Can only search up to 64 valves that release pressure");
+        let mut valves: Vec<&Valve> = positive_valves.iter().collect();
+        valves.sort();
+        Seen {
+            bits: valves.into_iter().enumerate().map(|(i, v)| (v.clone(), 1 << i)).collect(),
+            memo: Memo::new()
+        }
+    }
+
+    fn state(&self, curr: &Valve, remaining: &VisitList, curr_flow: u32, walk_remain: u32, time_remain: u32) -> State {
+        State {
+            at: curr.id,
+            closed: remaining.iter().fold(0, |closed, v| closed | self.bits[v]),
+            curr_flow,
+            walk_remain,
+            time_remain
+        }
+    }
+}
+
+fn find_max_release(
+    curr: &Valve,
+    distances: &DistMatrix,
+    remaining: VisitList,
+    curr_flow: u32,
+    walk_remain: u32,
+    time_remain: u32,
+    accumulation: u32,
+    best_seen: &mut u32,
+    seen: &mut Seen
+) -> u32
+{
+    let state = seen.state(curr, &remaining, curr_flow, walk_remain, time_remain);
+    if seen.memo.get(&state).is_some_and(|released| released >= accumulation) {
+        return 0; // We can prune here; the best release from this state has already been seen.
+    }
+    seen.memo.insert(state, accumulation);
+    explore(curr, distances, remaining, curr_flow, walk_remain, time_remain, accumulation, best_seen, seen)
+}
+
+fn explore(
+    curr: &Valve,
+    distances: &DistMatrix,
+    mut remaining: VisitList,
+    mut curr_flow: u32,
+    walk_remain: u32,
+    mut time_remain: u32,
+    accumulation: u32,
+    best_seen: &mut u32,
+    seen: &mut Seen
+) -> u32
+{
+    if time_remain == 0 {
+        return accumulation;
+    }
+
+    let possible_flow = max_possible(&remaining, accumulation, curr_flow, time_remain);
+    if possible_flow < *best_seen {
+        return 0; // We can prune here; this flow can't beat the best seen so far.
+    }
+
+    if walk_remain > 0 {
+        let max_release = find_max_release(
+            curr,
+            distances,
+            remaining,
+            curr_flow,
+            walk_remain - 1,
+            time_remain - 1,
+            accumulation + curr_flow,
+            best_seen,
+            seen
+        );
+        *best_seen = std::cmp::max::<u32>(max_release, *best_seen);
+        return max_release;
+    }
+
+    // Try releasing my valve, if it's in the list.
+    let mut this_valve_release = 0;
+
+    if remaining.contains(curr) {
+        remaining.remove(curr);
+        this_valve_release = curr_flow; // One flow happens while the valve is opening.
+        time_remain -= 1;
+        curr_flow += curr.release as u32;
+    }
+
+    if remaining.is_empty() {
+        // Nothing worth walking to, so use the remaining time to release at the current rate.
+        let max_release = accumulation + this_valve_release + time_remain * curr_flow;
+        *best_seen = std::cmp::max::<u32>(max_release, *best_seen);
+        return max_release;
+    }
+
+    // Try going to all the other valves and pick the maximum.
+    let mut release_walks = vec![];
+    for dest in &remaining {
+        let walk = distances[curr][dest];
+
+        let max_release_to_dest = find_max_release(
+            dest,
+            distances,
+            remaining.clone(),
+            curr_flow,
+            walk,
+            time_remain,
+            this_valve_release + accumulation,
+            best_seen,
+            seen
+        );
+
+        release_walks.push(max_release_to_dest);
+    }
+
+    let max_walk = release_walks.iter().max().unwrap();
+    *best_seen = std::cmp::max::<u32>(*max_walk, *best_seen);
+    return *max_walk;
+}
+
+fn max_possible(valves: &VisitList, accumulation: u32, curr_flow: u32, time_remaining: u32) -> u32 {
+    let mut releases: Vec<u32> = valves.iter().map(|v| v.release as u32).collect();
+    releases.sort();
+
+    let mut possible_flow = 0;
+    let mut time_countdown = time_remaining;
+    for r in releases.iter().rev() {
+        possible_flow += r * time_countdown;
+        time_countdown = time_countdown.saturating_sub(2);
+    }
+
+    return time_remaining * curr_flow + possible_flow + accumulation;
+}
+
+fn find_max_release_p2(
+    curr: &Valve,
+    curr_el: &Valve,
+    distances: &DistMatrix,
+    mut remaining: VisitList,
+    mut curr_flow: u32,
+    mut walk_remain: u32,
+    mut walk_remain_el: u32,
+    mut time_remain: u32,
+    accumulation: u32,
+    mut best_seen: &mut u32
+) -> u32
+{
+    if time_remain == 0 {
+        *best_seen = std::cmp::max::<u32>(accumulation, *best_seen);
+        return accumulation;
+    }
+
+    let possible_flow = max_possible(&remaining, accumulation, curr_flow, time_remain);
+    if possible_flow < *best_seen {
+        return possible_flow; // We can prune here; this flow can't beat the best seen so far.
+    }
+
+    if remaining.is_empty() {
+        // Nothing worth walking to, so use the remaining time to release at the current rate.
+        let max_release = accumulation + time_remain * curr_flow;
+        *best_seen = std::cmp::max::<u32>(max_release, *best_seen);
+        return max_release;
+    }
+
+    if walk_remain > 0 && walk_remain_el > 0 {
+        let max_release = find_max_release_p2(
+            curr,
+            curr_el,
+            distances,
+            remaining,
+            curr_flow,
+            walk_remain - 1,
+            walk_remain_el - 1,
+            time_remain - 1,
+            accumulation + curr_flow,
+            best_seen
+        );
+        *best_seen = std::cmp::max::<u32>(max_release, *best_seen);
+        return max_release;
+    }
+
+    // Try releasing one or both valves, if they're in the list.
+    let i_can_release = walk_remain == 0 && remaining.contains(curr);
+    let el_can_release = walk_remain_el == 0 && remaining.contains(curr_el);
+
+    if i_can_release || el_can_release {
+        if i_can_release && el_can_release {
+            remaining.remove(curr);
+            remaining.remove(curr_el);
+            let new_release = if curr == curr_el { curr.release as u32 } else { curr.release as u32 + curr_el.release as u32 };
+            let new_flow = curr_flow + new_release;
+
+            let max_release = find_max_release_p2(
+                curr,
+                curr_el,
+                distances,
+                remaining,
+                new_flow,
+                0,
+                0,
+                time_remain - 1,
+                accumulation + curr_flow,
+                best_seen
+            );
+            *best_seen = std::cmp::max::<u32>(max_release, *best_seen);
+            return max_release;
+        } else if i_can_release {
+            remaining.remove(curr);
+            time_remain -= 1;
+            walk_remain_el -= 1;
+
+            let new_flow = curr_flow + curr.release as u32;
+
+            // The elephant is still walking; we need to move to a new valve.
+            // The base-case release walk is doing nothing and letting the flow go as-is.
+            // (i.e., the current flow released while opening the valve, plus what was already accumulated, plus the new flow times the remaining time after the valve opened.)
+            let mut release_walks = vec![new_flow * time_remain + curr_flow + accumulation];
+            for dest in &remaining {
+                if dest == curr_el && remaining.len() > 1 {
+                    continue; // We shouldn't try to go where the elephant's going, unless it's the last destination.
+                }
+
+                let walk = distances[curr][dest];
+                let max_release_to_dest = find_max_release_p2(
+                    dest,
+                    curr_el,
+                    distances,
+                    remaining.clone(),
+                    new_flow,
+                    walk,
+                    walk_remain_el,
+                    time_remain,
+                    accumulation + curr_flow,
+                    best_seen
+                );
+
+                release_walks.push(max_release_to_dest);
+            }
+            let max_walk = release_walks.iter().max().unwrap();
+            *best_seen = std::cmp::max::<u32>(*max_walk, *best_seen);
+            return *max_walk;
+
+        } else if el_can_release {
+            remaining.remove(curr_el);
+            time_remain -= 1;
+            walk_remain -= 1;
+
+            let new_flow = curr_flow + curr_el.release as u32;
+
+            // We're still walking; the elephant needs to move to a new valve.
+            // The base-case release walk is doing nothing and letting the flow go as-is.
+            // (i.e., the current flow released while opening the valve, plus what was already accumulated, plus the new flow times the remaining time after the valve opened.)
+            let mut release_walks = vec![new_flow * time_remain + curr_flow + accumulation];
+            for dest_el in &remaining {
+                if dest_el == curr  && remaining.len() > 1 {
+                    continue; // The elephant shouldn't try to go where we're going, unless it's the last destination.
+                }
+                let walk_el = distances[curr_el][dest_el];
+                let max_release_to_dest = find_max_release_p2(
+                    curr,
+                    dest_el,
+                    distances,
+                    remaining.clone(),
+                    new_flow,
+                    walk_remain,
+                    walk_el,
+                    time_remain,
+                    accumulation + curr_flow,
+                    best_seen
+                );
+
+                release_walks.push(max_release_to_dest);
+            }
+            let max_walk = release_walks.iter().max().unwrap();
+            *best_seen = std::cmp::max::<u32>(*max_walk, *best_seen);
+            return *max_walk;
+        }
+    }
+
+    // Couldn't release a valve and we're both at the end of walks. Pick new destinations.
+    let mut release_walks = vec![];
+
+    if walk_remain == 0 && walk_remain_el == 0 {
+        // Neither of us was still walking.
+        for dest in &remaining {
+            for dest_el in &remaining {
+                if dest == dest_el && remaining.len() > 1 { // Don't walk to the same valve unless there's only one left.
+                    continue;
+                }
+
+                let walk = distances[curr][dest];
+                let walk_el = distances[curr_el][dest_el];
+
+                let max_release_to_dest = find_max_release_p2(
+                    dest,
+                    dest_el,
+                    distances,
+                    remaining.clone(),
+                    curr_flow,
+                    walk,
+                    walk_el,
+                    time_remain,
+                    accumulation,
+                    best_seen
+                );
+
+                release_walks.push(max_release_to_dest);
+            }
+        }
+    }
+
+    let max_walk = release_walks.iter().max().unwrap();
+    *best_seen = std::cmp::max::<u32>(*max_walk, *best_seen);
+    return *max_walk;
+}
+
+fn all_distances(tunnels: &Graph, valves: &VisitList) -> DistMatrix {
+    let mut result = DistMatrix::new();
+    for v in valves {
+        result.insert(
+            v.clone(),
+            distances_from(v, tunnels, valves)
+        );
+    }
+    return result;
+}
+
+// Every tunnel takes a minute, so a breadth-first search finds the shortest times.
+// Valves that can't be reached are u32::MAX minutes away.
+fn distances_from(start: &Valve, tunnels: &Graph, valves: &VisitList) -> Distances {
+    let search = bfs(tunnels, start.clone());
+    valves.iter().map(
+        |v| (v.clone(), search.distance(v).map_or(u32::MAX, |d| d as Distance))
+    ).collect()
+}
+
+// Each line's valve and the valves its tunnels lead to, skipping lines that don't scan (`parse`
+// reports those).
+fn scan_tunnels(input: &Input) -> Vec<(String, Vec<String>)> {
+    input.lines()
+        .filter_map(|line| scan_fmt![line, "Valve {} has flow rate={*d}; {*/tunnels lead|tunnel leads/} to valve{*/s?/} {/.*/}", String, String].ok())
+        .map(|(valve, to)| (valve, to.split(", ").map(String::from).collect()))
+        .collect()
+}
+
+impl Validate for Day16 {
+    const ASSUMPTIONS: &'static [Assumption] = &[
+        Assumption {
+            description: "Valve IDs are two capital letters",
+            check: |input| {
+                let tunnels = scan_tunnels(input);
+                let mut ids = tunnels.iter().flat_map(|(valve, to)| std::iter::once(valve).chain(to));
+                match ids.find(|id| id.len() != 2 || !id.chars().all(|c| c.is_ascii_uppercase())) {
+                    Some(id) => Err(format!["\"{id}\" isn't"]),
+                    None => Ok(())
+                }
+            }
+        },
+        Assumption {
+            description: "There's a valve AA to start from",
+            check: |input| match scan_tunnels(input).iter().any(|(valve, _)| valve == "AA") {
+                true => Ok(()),
+                false => Err("There's no line for AA".to_string())
+            }
+        },
+        Assumption {
+            description: "Tunnels only lead to valves that have a line of their own",
+            check: |input| {
+                let tunnels = scan_tunnels(input);
+                let valves: HashSet<&String> = tunnels.iter().map(|(valve, _)| valve).collect();
+                for (valve, to) in &tunnels {
+                    if let Some(missing) = to.iter().find(|to| !valves.contains(to)) {
+                        return Err(format!["{valve} has a tunnel to {missing}, which isn't listed"]);
+                    }
+                }
+                Ok(())
+            }
+        }
+    ];
+}
+
+impl Generate for Day16 {
+    const PARAMS: &'static [Param] = &[
+        Param { name: "valves", default: 60, help: "how many valves, including AA (at least 2, so AA has a tunnel)" },
+        Param { name: "working", default: 15, help: "how many of them release pressure (AA never does)" },
+        Param { name: "max_flow", default: 25, help: "the highest flow rate" },
+        Param { name: "loops", default: 10, help: "how many tunnels to add beyond the ones connecting every valve" }
+    ];
+
+    // A random tree of tunnels from AA, plus a few extra tunnels to make loops.
+    fn generate(rng: &mut Rng, params: &Params) -> String {
+        let count = params.get("valves").clamp(2, 26 * 26) as usize;
+        let mut names: Vec<String> = ('A'..='Z')
+            .flat_map(|a| ('A'..='Z').map(move |b| format!["{a}{b}"]))
+            .filter(|name| name != "AA")
+            .collect();
+        rng.shuffle(&mut names);
+        names.insert(0, "AA".to_string());
+        names.truncate(count);
+
+        let mut tunnels: Vec<Vec<usize>> = vec![vec![]; count];
+        let mut connect = |a: usize, b: usize| if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        };
+        for valve in 1..count {
+            connect(valve, rng.below(valve as u64) as usize);
+        }
+        for _ in 0..params.get("loops") {
+            connect(rng.below(count as u64) as usize, rng.below(count as u64) as usize);
+        }
+
+        let mut flows = vec![0; count];
+        let mut working: Vec<usize> = (1..count).collect();
+        rng.shuffle(&mut working);
+        for &valve in working.iter().take(params.get("working") as usize) {
+            flows[valve] = rng.range(1..=params.get("max_flow").max(1));
+        }
+
+        let mut lines: Vec<String> = (0..count).map(|valve| {
+            let to: Vec<&str> = tunnels[valve].iter().map(|&v| names[v].as_str()).collect();
+            let lead = if to.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+            format!["Valve {} has flow rate={}; {lead} {}\n", names[valve], flows[valve], to.join(", ")]
+        }).collect();
+        rng.shuffle(&mut lines);
+        lines.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances_from_test() {
+        let valves: VisitList = HashSet::from([
+            Valve::from_id("AA"),
+            Valve::from_id("BB"),
+            Valve::from_id("CC"),
+            Valve::from_id("DD")
+        ]);
+        let graph: Graph = HashMap::from([
+            (Valve::from_id("AA"), Vec::from([Valve::from_id("BB"), Valve::from_id("CC")])),
+            (Valve::from_id("BB"), Vec::from([Valve::from_id("AA")])),
+            (Valve::from_id("CC"), Vec::from([Valve::from_id("AA"), Valve::from_id("DD")])),
+            (Valve::from_id("DD"), Vec::from([Valve::from_id("CC")])),
+        ]);
+        
+        let actual = distances_from(&Valve::from_id("AA"), &graph, &valves);
+        assert_eq!(actual[&Valve::from_id("AA")], 0);
+        assert_eq!(actual[&Valve::from_id("BB")], 1);
+        assert_eq!(actual[&Valve::from_id("CC")], 1);
+        assert_eq!(actual[&Valve::from_id("DD")], 2);
+    }
+
+    #[test]
+    fn sample_produces_correct_output_part1() {
+        let (dists, valves) = parse("sample.txt");
+        let release = day16_p1(&dists, &valves);
+        assert_eq!(release, 1651);
+    }
+
+    #[test]
+    fn sample_produces_correct_output_part2() {
+        let (dists, valves) = parse("sample.txt");
+        let release = day16_p2(&dists, &valves);
+        assert_eq!(release, 1707);
+    }
+
+    #[test]
+    fn linear_part1() {
+        let (dists, valves) = parse("linear.txt");
+        let release = day16_p1(&dists, &valves);
+        assert_eq!(release, 4700);
+    }
+
+    #[test]
+    fn linear_part2() {
+        let (dists, valves) = parse("linear.txt");
+        let release = day16_p2(&dists, &valves);
+        assert_eq!(release, 4075);
+    }
+
+    #[test]
+    fn generated_valves() {
+        let args = ["valves=12", "working=4"].map(String::from);
+        let input = utils::generate::generate::<Day16>(16, &args).unwrap();
+        assert_eq!(input, utils::generate::generate::<Day16>(16, &args).unwrap());
+        assert_eq!(input.lines().count(), 12);
+
+        let (dists, valves) = parse_input(&Input::from(input.as_str())).unwrap();
+        assert_eq!(valves.len(), 4);
+        assert_eq!(dists[&Valve::from_id("AA")].len(), 12, "Every valve should be reachable from AA");
+        assert!(day16_p1(&dists, &valves) > 0);
+    }
+
+    #[test]
+    fn generated_smallest() {
+        let args = ["valves=0", "working=0", "max_flow=0", "loops=0"].map(String::from);
+        let input = utils::generate::generate::<Day16>(16, &args).unwrap();
+        let input = Input::from(input.as_str());
+        assert_eq!(utils::validate::validate::<Day16>(&input), Ok(vec![]));
+        let (dists, valves) = parse_input(&input).unwrap();
+        assert_eq!((dists.len(), valves.len()), (2, 0));
+        assert_eq!((day16_p1(&dists, &valves), day16_p2(&dists, &valves)), (0, 0));
+
+        let input = utils::generate::generate::<Day16>(16, &["valves=1".to_string(), "working=5".to_string()]).unwrap();
+        let (_, valves) = parse_input(&Input::from(input.as_str())).unwrap();
+        assert_eq!(valves.len(), 1);
+    }
+
+    #[test]
+    fn assumptions() {
+        use utils::validate::validate;
+        assert_eq!(validate::<Day16>(&Input::load("sample.txt").unwrap()), Ok(vec![]));
+
+        let input = Input::from("Valve AA has flow rate=0; tunnels lead to valves B, CC\nValve B has flow rate=3; tunnel leads to valve AA\nValve CC has flow rate=1; tunnel leads to valve AA");
+        let violations = validate::<Day16>(&input).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].problem, "\"B\" isn't");
+
+        let input = Input::from("Valve BB has flow rate=0; tunnels lead to valves CC, DD\nValve CC has flow rate=3; tunnel leads to valve BB");
+        let violations: Vec<String> = validate::<Day16>(&input).unwrap().iter().map(|v| v.problem.clone()).collect();
+        assert_eq!(violations, ["There's no line for AA", "BB has a tunnel to DD, which isn't listed"]);
+
+    }
+
+    #[test]
+    fn aa_releases_pressure() {
+        let input = Input::from("Valve AA has flow rate=4; tunnel leads to valve BB\nValve BB has flow rate=3; tunnel leads to valve AA");
+        assert_eq!(utils::validate::validate::<Day16>(&input), Ok(vec![]));
+        let (dists, valves) = parse_input(&input).unwrap();
+        // Open AA straight away, then BB.
+        assert_eq!(day16_p1(&dists, &valves), 4 * 29 + 3 * 27);
+        // One opens AA while the other walks to BB.
+        assert_eq!(day16_p2(&dists, &valves), 4 * 25 + 3 * 24);
+    }
+
+    #[test]
+    fn puzzle_input_produces_correct_output_part1() {
+        let (dists, valves) = parse("input.txt");
+        let release = day16_p1(&dists, &valves);
+        assert_eq!(release, 1376);
+    }
+
+    #[test]
+    fn puzzle_input_part2() {
+        let (dists, valves) = parse("input.txt");
+        let release = day16_p2(&dists, &valves);
+        assert![release < 1956];
+        assert![release < 1954];
+        assert![release != 1877];
+        assert_eq!(release, 1933);
+    }
+}
\ No newline at end of file
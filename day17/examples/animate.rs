@@ -0,0 +1,11 @@
+use day17::{drop_rocks, Day17};
+use utils::render::animate_from_args;
+use utils::solution::Solution;
+
+fn main() {
+    // Part 2 drops the same rocks, just a trillion of them, so either part animates part 1's 2022.
+    animate_from_args(|input, _part, recorder| {
+        drop_rocks(&Day17::parse(input)?, 2022, recorder);
+        Ok(())
+    });
+}
@@ -0,0 +1,530 @@
+use std::fmt;
+use itertools::Itertools;
+use std::time::{Instant};
+use utils::error::InputError;
+use utils::input::Input;
+use utils::render::{Cell, Frame, Recorder};
+use utils::solution::Solution;
+use tracing::{debug, info};
+
+struct Board<'input> {
+    board: Vec<u8>,
+    elided_height: u64,
+    jets: &'input str,
+    shape_fall: Vec<u8>,
+    shape_idx: usize
+}
+
+impl<'input> Board<'input> {
+    fn new(jets: &'input str) -> Self {
+        Self {
+            board: vec![],
+            jets,
+            elided_height: 0,
+            shape_fall: vec![],
+            shape_idx: 0
+        }
+    }
+
+    // Simulates blocks falling and being pushed by jets
+    // Returns the tower height after a number of steps.
+    fn simulate(&mut self, cycles: u64) -> u64 {
+        self.simulate_recorded(cycles, None)
+    }
+
+    // Simulate, recording the board after each jet push and fall.
+    fn simulate_recorded(&mut self, cycles: u64, mut recorder: Option<&mut Recorder>) -> u64 {
+        let mut count = 0u64;
+        let mut jetiter = self.jets.chars().cycle();
+
+        let start = Instant::now();
+
+        self.start_new_shape(count);
+
+        while count < cycles {
+            self.push(jetiter.next().unwrap());
+            let stopped = self.fall();
+            if let Some(recorder) = recorder.as_deref_mut() {
+                recorder.record(self);
+            }
+            if stopped {
+                if self.board.len() > 35 {
+                    // When the board gets too long...
+                    // Find the highest location where two rows cut off any access below them.
+                    // Elide the board by removing lower row and cache the elided height at that point.
+                    self.elide();
+                }
+
+                count += 1;
+                self.start_new_shape(count);
+
+                fn power_of(mut num: u64, base: u64) -> bool {
+                    while num >= base && num % base == 0 {
+                        num /= base;
+                    }
+                    return num == 1;
+                }
+
+                if power_of(count, 16) {
+                    let highest = self.board.len() as u64 - self.board.iter().rev().find_position(|&&row| row > 0).unwrap().0 as u64;
+                    info!(count, height = highest + self.elided_height, elided = self.elided_height, seconds = start.elapsed().as_secs(), "Checkpoint");
+                }
+            }
+        }
+
+        // Find the highest row that has a '#' (i.e. is >0)
+        let highest = self.board.len() as u64 - self.board.iter().rev().find_position(|&&row| row > 0).unwrap().0 as u64;
+        debug!(highest, board_length = self.board.len(), seconds = start.elapsed().as_secs(), "Completed");
+        return highest + self.elided_height;
+    }
+
+    fn elide(&mut self) {
+        // Find the highest two rows where there is a # in either row for each column. E.g.
+        // .#..#..
+        // #.#####
+        // These are impassable, so remove the bottom most row and add its height to the elided height.
+
+        // Find the first two rows (backward) where, for each column, one or both of the rows has a '#'
+        let position_elem = self.board
+            .windows(2)
+            .rev()
+            .find_position(
+                |windows| 
+                    windows[0] | windows[1] == 0b111_1111
+            );
+        if position_elem.is_none() {
+            return;
+        }
+
+        let idx_to_elide = self.board.len() - (position_elem.unwrap().0 + 1);
+        self.board.drain(0..idx_to_elide);
+        self.elided_height += idx_to_elide as u64;
+    }
+
+    // Returns true if falling stopped.
+    fn fall(&mut self) -> bool {
+        // If the shape falling would collide with anything, including the bottom, freeze it where it is.
+        let mut collides = self.shape_idx == 0;
+
+        if !collides {
+            // Check collision with shapes below
+            for i in 0..self.shape_fall.len() {
+                let below_idx = self.shape_idx - 1;
+                let b = self.board[below_idx + i];
+                let s = self.shape_fall[i];
+                if b & s > 0 {
+                    collides = true;
+                    break;
+                }
+            }
+        }
+
+        if collides
+        {
+            // Lock the shape into the board.
+            for i in 0..self.shape_fall.len() {
+                self.board[i + self.shape_idx] |= self.shape_fall[i];
+            }
+            self.shape_fall.clear();
+            return true;
+        }
+
+        self.shape_idx -= 1;
+        return false;
+    }
+
+    fn push(&mut self, dir: char) {
+        // Skip pushing if any part of the shape would hit a wall
+        // (i.e. a 1 is on the left or right for any line of the shape, depending on direction.)
+
+        // IDEA: automatically skip repeats if you are against a wall and know you're at the top.
+
+        let wall_adjacent = match dir {
+            '<' => 0b100_0000,
+            '>' => 0b000_0001,
+            _ => panic!("Impossible")
+        };
+
+        for i in 0..self.shape_fall.len() {
+            if self.shape_fall[i] & wall_adjacent > 0 {
+                // Some part of shape will collide with the wall
+                return;
+            }
+        }
+
+        for i in 0..self.shape_fall.len() {
+            self.shape_fall[i] = match dir {
+                '<' => self.shape_fall[i] << 1,
+                '>' => self.shape_fall[i] >> 1,
+                _ => panic!("Impossible!")
+            };
+        }
+
+        let mut collision = false;
+        for i in 0..self.shape_fall.len() {
+            let b = self.board[self.shape_idx + i];
+            let m = self.shape_fall[i];
+            if b & m > 0 {
+                collision = true;
+                break;
+            }
+        }
+
+        if collision {
+            // Undo the shift if it collided
+            for i in 0..self.shape_fall.len() {
+                self.shape_fall[i] = match dir {
+                    '<' => self.shape_fall[i] >> 1,
+                    '>' => self.shape_fall[i] << 1,
+                    _ => panic!("Impossible!")
+                };
+            }
+        }
+    }
+
+    fn start_new_shape(&mut self, counter: u64) {
+        // Find the first empty row, make sure there are three empty lines above it.
+        let first_empty = self.board.iter().find_position(|&&bits| bits == 0);
+        let first_empty = if first_empty.is_some() { first_empty.unwrap().0 } else { 0 };
+        let lines_to_add: i32 = 3 - (self.board.len() - first_empty) as i32;
+        if lines_to_add < 0 {
+            for _ in lines_to_add..0 {
+                self.board.pop(); // IDEA: instead of popping, start shape_idx at the appropriate place.
+            }
+        } else {
+            for _ in 0..lines_to_add {
+                self.board.push(0);
+            }
+        }
+    
+        self.shape_idx = self.board.len();
+        match counter % 5 {
+            0 => {
+                self.shape_fall.push(0b0011110);
+                self.board.push(0);
+            },
+            1 => {
+                self.shape_fall.push(0b0001000);
+                self.shape_fall.push(0b0011100);
+                self.shape_fall.push(0b0001000);
+                for _ in 0..3 { self.board.push(0); }
+            },
+            2 => {
+                self.shape_fall.push(0b0011100);
+                self.shape_fall.push(0b0000100);
+                self.shape_fall.push(0b0000100);
+                for _ in 0..3 { self.board.push(0); }
+            },
+            3 => {
+                self.shape_fall.push(0b0010000);
+                self.shape_fall.push(0b0010000);
+                self.shape_fall.push(0b0010000);
+                self.shape_fall.push(0b0010000);
+                for _ in 0..4 { self.board.push(0); }
+            },
+            4 => {
+                self.shape_fall.push(0b0011000);
+                self.shape_fall.push(0b0011000);
+                for _ in 0..2 { self.board.push(0); }
+            },
+            _ => panic!("Impossible!")
+        }
+    }
+}
+
+const ROCK: Cell = Cell::new('#', [150, 140, 130]);
+const FALLING: Cell = Cell::new('@', [230, 120, 40]);
+const AIR: Cell = Cell::new('.', [20, 20, 30]);
+
+// The board (what hasn't been elided), with the top row first and the falling shape drawn over it.
+impl Frame for Board<'_> {
+    fn rows(&self) -> usize {
+        std::cmp::max(self.board.len(), self.shape_idx + self.shape_fall.len())
+    }
+
+    fn cols(&self) -> usize {
+        7
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Cell {
+        let idx = self.rows() - 1 - row;
+        let mask = 0b0100_0000 >> col;
+        let shape = idx.checked_sub(self.shape_idx).and_then(|i| self.shape_fall.get(i)).copied().unwrap_or(0);
+        if shape & mask > 0 {
+            FALLING
+        } else if self.board.get(idx).copied().unwrap_or(0) & mask > 0 {
+            ROCK
+        } else {
+            AIR
+        }
+    }
+}
+
+impl fmt::Display for Board<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elision_msg = if self.elided_height > 0 { format!["\n[and {} rows removed]", self.elided_height] } else { "".to_string() };
+        write!(f, "{}{}", self.text(), elision_msg)
+    }
+}
+
+/// Drop `rocks` rocks, recording the board as they fall, and return the tower's height.
+pub fn drop_rocks(jets: &str, rocks: u64, recorder: &mut Recorder) -> u64 {
+    Board::new(jets).simulate_recorded(rocks, Some(recorder))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let jets = input.text();
+        match jets.chars().find(|c| !['<', '>'].contains(c)) {
+            None => Ok(jets.to_string()),
+            Some(c) => Err(InputError::new(format!["Unexpected jet '{c}'"]).on_line(1, jets))
+        }
+    }
+
+    // The height of the tower after 2022 rocks.
+    fn part1(jets: &Self::Input) -> u64 {
+        let mut board = Board::new(jets);
+        board.simulate(2022)
+        // println!["{board}"];
+    }
+
+    // The height of the tower after a trillion rocks.
+    fn part2(jets: &Self::Input) -> u64 {
+        let mut board = Board::new(jets);
+        board.simulate(1_000_000_000_000)
+        // println!["{board}"];
+
+        // For sample, expecting 1_514_285_714_288
+
+        // I simulated with 1 billion (instead of 1 trillion). It took a *very* long time.
+        // It also gave me       1_514_285_720
+        // On a million:         1_514_288
+        // Suspicious! There must be something mathy happening here. In fact,
+        // I bet I could get much better performance by bitmasking instead of using characters.
+
+        // On input...
+        // 1_000 ->                        1_525
+        // 10_000 ->                      15_403
+        // 100_000 ->                    154_050
+        // 500_000 ->                    770_375
+        // 1_000_000 ->                1_540_796
+        // 10_000_000 ->              15_408_043
+        // 50_000_000 ->              77_040_215
+        // 100_000_000 gave me       154_080_459
+        // 500_000_000 gave me       770_402_288
+        // 1_000_000_000  ->       1_540_804_578
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape0() {
+        let mut board = Board::new("");
+        board.start_new_shape(0);
+        assert_eq![board.to_string(),
+            [ "..@@@@.",
+              ".......",
+              ".......",
+              "......."
+            ].join("\n")
+        ];
+    }
+
+    #[test]
+    fn shape1() {
+        let mut board = Board::new("");
+        board.start_new_shape(1);
+        assert_eq![board.to_string(), 
+            [ "...@...",
+              "..@@@..",
+              "...@...",
+              ".......",
+              ".......",
+              "......."
+            ].join("\n")
+        ];
+    }
+
+    #[test]
+    fn shape2() {
+        let mut board = Board::new("");
+        board.start_new_shape(2);
+        assert_eq![board.to_string(), 
+            [ "....@..",
+              "....@..",
+              "..@@@..",
+              ".......",
+              ".......",
+              "......."
+            ].join("\n")
+        ];
+    }
+
+    #[test]
+    fn shape3() {
+        let mut board = Board::new("");
+        board.start_new_shape(3);
+        assert_eq![board.to_string(), 
+            [ "..@....",
+              "..@....",
+              "..@....",
+              "..@....",
+              ".......",
+              ".......",
+              "......."
+            ].join("\n")
+        ];
+    }
+
+    #[test]
+    fn shape4() {
+        let mut board = Board::new("");
+        board.start_new_shape(4);
+        assert_eq![board.to_string(), 
+            [ "..@@...",
+              "..@@...",
+              ".......",
+              ".......",
+              "......."
+            ].join("\n")
+        ];
+    }
+
+    #[test]
+    fn shape0_fall_to_floor() {
+        let mut board = Board::new("");
+        board.start_new_shape(0);
+        board.fall();
+        board.fall();
+        let pen = board.fall();
+        let res = board.fall();
+        assert_eq![board.to_string(),
+            [ ".......",
+              ".......",
+              ".......",
+              "..####."
+            ].join("\n")
+        ];
+        assert_eq!(pen, false);
+        assert_eq!(res, true);
+    }
+
+    #[test]
+    fn shape0_fall_to_floor_with_pushing() {
+        let mut board = Board::new("<<<<");
+        board.start_new_shape(0);
+        board.push('<');
+        board.fall();
+        board.push('<');
+        board.fall();
+        board.push('<');
+        let pen = board.fall();
+        board.push('>');
+        let res = board.fall();
+        assert_eq![board.to_string(),
+            [ ".......",
+              ".......",
+              ".......",
+              ".####.."
+            ].join("\n")
+        ];
+        assert_eq!(pen, false);
+        assert_eq!(res, true);
+    }
+
+    #[test]
+    fn shape0_and_1_fall_to_floor() {
+        let mut board = Board::new("");
+        board.start_new_shape(0);
+        board.fall();
+        board.fall();
+        board.fall();
+        board.fall();
+        board.start_new_shape(1);
+        board.fall();
+        board.fall();
+        let pen = board.fall();
+        let res = board.fall();
+        assert_eq![board.to_string(),
+            [ ".......",
+              ".......",
+              ".......",
+              "...#...",
+              "..###..",
+              "...#...",
+              "..####."
+            ].join("\n")
+        ];
+        assert_eq!(pen, false);
+        assert_eq!(res, true);
+    }
+
+    #[test]
+    fn elision() {
+        let mut board = Board::new("");
+
+        // Drop two horizontal bars. The lower one should get elided.
+        board.start_new_shape(0);
+        for _ in 0..4 {
+            board.push('<');
+            board.fall();
+        }
+        board.start_new_shape(0);
+        for _ in 0..4 {
+            board.push('>');
+            board.fall();
+        }
+        assert_eq![board.to_string(),
+            [ ".......",
+              ".......",
+              ".......",
+              "...####",
+              "####..."
+            ].join("\n")
+        ];
+        board.elide();
+        assert_eq![board.to_string(),
+            [ ".......",
+              ".......",
+              ".......",
+              "...####",
+              "[and 1 rows removed]"
+            ].join("\n")
+        ];
+        assert_eq![board.elided_height, 1];
+    }
+
+    #[test]
+    fn no_elision() {
+        let mut board = Board::new("");
+
+        // Drop two horizontal bars. The lower one should get elided.
+        board.start_new_shape(0);
+        for _ in 0..4 {
+            board.push('<');
+            board.fall();
+        }
+        board.elide();
+        assert_eq![board.to_string(),
+            [ ".......",
+              ".......",
+              ".......",
+              "####..."
+            ].join("\n")
+        ];
+        assert_eq![board.elided_height, 0];
+    }
+}
\ No newline at end of file
@@ -1,18 +1,33 @@
 use scan_fmt::scan_fmt;
+use serde_json::Value;
 
 fn main() {
-    // Read in the file provided as the first argument.
+    // Read in the file provided as the first argument: either the records from `aoc 17 --ndjson`, or
+    // an older log of `println!`ed checkpoints like the one checked in.
     let path = utils::args_iter().next().expect("Missing argument");
-    let input = std::fs::read_to_string(&path).expect(&format!["Couldn't find file \"{path}\""]);
+    let input = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("Couldn't find file \"{path}\""));
 
-    let max_diff = input.split("\n")
-        .map(
-            |line| {
-                let res = scan_fmt![line, "At {*d}, height is {} ({} elided), time {*/.*/}", u64, u64];
-                let (height, elided) = res.unwrap();
-                height - elided
-            }
-        ).max().unwrap();
+    // Day 17 logs a checkpoint every so often with the tower's height and how much of it was elided.
+    let max_diff = input.lines()
+        .filter(|line| !line.is_empty())
+        .filter_map(checkpoint)
+        .map(|(height, elided)| height - elided)
+        .max().unwrap();
 
     println!["Max difference is {max_diff}"];
-}
\ No newline at end of file
+}
+
+// The height and elided height from a checkpoint line, or `None` for any other record.
+fn checkpoint(line: &str) -> Option<(u64, u64)> {
+    if !line.starts_with('{') {
+        let res = scan_fmt![line, "At {*d}, height is {} ({} elided), time {*/.*/}", u64, u64];
+        return Some(res.unwrap_or_else(|_| panic!("Not a checkpoint: {line}")));
+    }
+
+    let record = serde_json::from_str::<Value>(line).unwrap_or_else(|_| panic!("Not a JSON record: {line}"));
+    if record["type"] != "diagnostic" || record["fields"]["message"] != "Checkpoint" {
+        return None;
+    }
+    let fields = &record["fields"];
+    Some((fields["height"].as_u64().unwrap(), fields["elided"].as_u64().unwrap()))
+}
@@ -0,0 +1,455 @@
+use std::collections::{HashSet, VecDeque};
+
+use utils::coordinates::Coord;
+use scan_fmt::scan_fmt;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::search::{bfs, Graph};
+use utils::generate::{Generate, Param, Params, Rng};
+use utils::solution::Solution;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = HashSet<Coord>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let drops: Vec<Coord> = input.parse_lines(
+            |line| {
+                let coord = scan_fmt!(line, "{},{},{}", i32, i32, i32);
+                coord.map(Coord::from)
+            }
+        )?;
+        Ok(drops.into_iter().collect())
+    }
+
+    // Total surface area.
+    fn part1(drops: &Self::Input) -> u32 {
+        let mut sides = 0;
+        let mut part1_drops = drops.clone();
+        while !part1_drops.is_empty() {
+            sides += sides_starting_at_first(&mut part1_drops, false).0;
+        }
+        sides
+    }
+
+    // Exterior surface area.
+    fn part2(drops: &Self::Input) -> u32 {
+        // Find the full volume the droplets might be in (with 1 voxel of padding around them).
+        // Then, fill a set with all the air space around the droplets. Every side of a drop
+        // that touches that air is on the outside.
+        let minmax = containing_volume(drops);
+        let air = fill_volume(minmax, drops);
+
+        let mut sides = 0;
+        for drop in drops {
+            sides += DIRS.iter().filter(|&&d| air.contains(&(*drop + d))).count() as u32;
+        }
+        sides
+    }
+}
+
+const DIRS: [Coord; 6] = [
+    Coord { x: 1, y: 0, z: 0 },  // Right
+    Coord { x: -1, y: 0, z: 0 }, // Left
+    Coord { x: 0, y: 1, z: 0 },  // Forward
+    Coord { x: 0, y: -1, z: 0 }, // Back
+    Coord { x: 0, y: 0, z: 1 },  // Up
+    Coord { x: 0, y: 0, z: -1 }, // Down
+];
+
+fn containing_volume(coords: &HashSet<Coord>) -> (Coord, Coord) {
+    let mut min: Coord = coords.iter().next().unwrap().clone();
+    let mut max: Coord = min.clone();
+    for c in coords {
+        min.x = std::cmp::min(min.x, c.x - 1);
+        min.y = std::cmp::min(min.y, c.y - 1);
+        min.z = std::cmp::min(min.z, c.z - 1);
+
+        max.x = std::cmp::max(max.x, c.x + 1);
+        max.y = std::cmp::max(max.y, c.y + 1);
+        max.z = std::cmp::max(max.z, c.z + 1);
+    }
+
+    return (min, max);
+}
+
+// Find any path out of the bounds of the drops. Returns true if a path exists and false if not.
+// Either way, returns the set of visited coordinates, which will all be either in or out of the drops.
+fn can_dfs_out(coord: Coord, drops: &HashSet<Coord>) -> (bool, Vec<Coord>) {
+    let (min, max) = containing_volume(drops);
+    let mut visited = vec![];
+
+    let dirs = [
+        Coord::new(1,0,0),  // Right
+        Coord::new(-1,0,0), // Left
+        Coord::new(0,1,0), // Forward
+        Coord::new(0,-1,0), // Back
+        Coord::new(0,0,1),  // Up
+        Coord::new(0,0,-1),  // Down
+    ];
+
+    let mut stack = vec![coord];
+    while !stack.is_empty() {
+        let curr = stack.pop().unwrap();
+        visited.push(curr);
+    
+        for d in dirs {
+            let next = curr + d;
+            if visited.contains(&next) || drops.contains(&next) { continue; }
+
+            if next.x < min.x || next.x > max.x ||
+               next.y < min.y || next.y > max.y ||
+               next.z < min.z || next.z > max.z
+            {
+                // Found a way out!
+                visited.push(next);
+                return (true, visited);
+            }
+
+            stack.push(next);
+        }
+    }
+
+    return (false, visited);
+}
+
+// The air in the box from min to max, i.e. everything that isn't a drop.
+struct Air<'a> {
+    min: Coord,
+    max: Coord,
+    drops: &'a HashSet<Coord>
+}
+
+impl Graph for Air<'_> {
+    type Node = Coord;
+
+    fn neighbors(&self, curr: &Coord) -> Vec<Coord> {
+        DIRS.iter().map(|&d| *curr + d).filter(|next| {
+            let inside_bounds =
+                next.x >= self.min.x && next.x <= self.max.x &&
+                next.y >= self.min.y && next.y <= self.max.y &&
+                next.z >= self.min.z && next.z <= self.max.z;
+            inside_bounds && !self.drops.contains(next)
+        }).collect()
+    }
+}
+
+// All the air reachable from the min corner without leaving the box.
+fn fill_volume((min, max): (Coord, Coord), drops: &HashSet<Coord>) -> HashSet<Coord> {
+    let search = bfs(&Air { min, max, drops }, min);
+    return search.distances().keys().copied().collect();
+}
+
+fn sides_starting_at_first(coords: &mut HashSet<Coord>, dfs_holes: bool) -> (u32, HashSet<Coord>) {
+    if coords.is_empty() {
+        panic!("Don't give me an empty set.");
+    }
+
+    let dirs = [
+        Coord::new(1,0,0),  // Right
+        Coord::new(-1,0,0), // Left
+        Coord::new(0,1,0), // Forward
+        Coord::new(0,-1,0), // Back
+        Coord::new(0,0,1),  // Up
+        Coord::new(0,0,-1),  // Down
+    ];
+    let diagonals =[
+        // Diagonals in x
+        Coord::new(0, 1, -1),
+        Coord::new(0, 1, 1),
+        Coord::new(0, -1, -1),
+        Coord::new(0, -1, -1),
+
+        // Diagonals in y
+        Coord::new(1, 0, -1),
+        Coord::new(1, 0, 1),
+        Coord::new(-1, 0, -1),
+        Coord::new(-1, 0, -1),
+
+        // Diagonals in z
+        Coord::new(1, -1, 0),
+        Coord::new(1, 1, 0),
+        Coord::new(-1,-1, 0),
+        Coord::new(-1,-1, 0)
+    ];
+
+    let first: Coord = *coords.iter().next().unwrap();
+    let mut q = VecDeque::from([first]);
+    let mut to_remove = HashSet::from([first]);
+    let mut result = 0;
+    while !q.is_empty() {
+        let curr = q.pop_back().unwrap();
+
+        // Find everything that touches curr on a side.
+        for d in dirs {
+            let next = curr + d;
+            if to_remove.contains(&next) {
+                continue; // Ignore a side that *had* a connection but doesn't any longer.
+            }
+
+            if coords.contains(&next) {
+                q.push_front(next);
+                to_remove.insert(next); // Current can be removed from the set.
+            } else  {
+                result += 1;
+
+                if dfs_holes {
+                    let (exitable, _) = can_dfs_out(next, coords);
+                    if !exitable {
+                        result -= 1; // Found an empty side that was interior. Don't count it.
+                    }
+                }
+            }
+        }
+
+        // Incorporate the diagonals; only search, don't add these connections as sides.
+        for d in diagonals {
+            let next = curr + d;
+            if to_remove.contains(&next) {
+                continue;
+            }
+
+            if coords.contains(&next) {
+                q.push_front(next);
+                to_remove.insert(next); // Current can be removed from the set.
+            }
+        }
+    }
+
+    // Remove the ones on our remove list.
+    let blob = coords.extract_if(|c| to_remove.contains(&c)).collect();
+
+    return (result, blob);
+}
+
+// The biggest params, so that coordinates stay small and generating doesn't take all day.
+const MAX_SIZE: u64 = 100;
+const MAX_BLOBS: u64 = 100;
+const MAX_CAVITIES: u64 = 100;
+
+impl Generate for Day18 {
+    const PARAMS: &'static [Param] = &[
+        Param { name: "size", default: 20, help: "how many cubes fit along each side of the space the droplet is in (at most 100)" },
+        Param { name: "blobs", default: 6, help: "how many rough balls make up the droplet (at most 100)" },
+        Param { name: "cavities", default: 4, help: "how many pockets of air to seal inside it (at most 100, fewer if they don't fit)" }
+    ];
+
+    fn check(params: &Params) -> Result<(), String> {
+        for (name, range) in [("size", 1..=MAX_SIZE), ("blobs", 0..=MAX_BLOBS), ("cavities", 0..=MAX_CAVITIES)] {
+            let value = params.get(name);
+            if !range.contains(&value) {
+                return Err(format!["{name} has to be from {} to {}, not {value}", range.start(), range.end()]);
+            }
+        }
+        Ok(())
+    }
+
+    fn generate(rng: &mut Rng, params: &Params) -> String {
+        let size = params.get("size") as i32;
+        let mut drops = HashSet::new();
+        for _ in 0..params.get("blobs") {
+            let radius = rng.range((size as u64 / 6).max(1)..=(size as u64 / 3).max(1)) as i32;
+            let center = Coord::new(
+                rng.below(size as u64) as i32, rng.below(size as u64) as i32, rng.below(size as u64) as i32
+            );
+            for x in 0.max(center.x - radius - 1)..size.min(center.x + radius + 2) {
+                for y in 0.max(center.y - radius - 1)..size.min(center.y + radius + 2) {
+                    for z in 0.max(center.z - radius - 1)..size.min(center.z + radius + 2) {
+                        let d2 = (x - center.x).pow(2) + (y - center.y).pow(2) + (z - center.z).pow(2);
+                        // The cubes just outside the ball are a coin flip, to make the surface rough.
+                        if d2 <= radius.pow(2) || d2 <= (radius + 1).pow(2) && rng.chance(50) {
+                            drops.insert(Coord::new(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Hollow out a cube (and maybe some of its neighbors) where everything within two steps is
+        // lava, so that the pocket is sealed in.
+        let solid = |drops: &HashSet<Coord>, c: Coord| (-2..=2).all(|x| (-2..=2).all(|y| (-2..=2).all(|z|
+            drops.contains(&(c + Coord::new(x, y, z)))
+        )));
+        let mut candidates: Vec<Coord> = drops.iter().copied().collect();
+        candidates.sort_by_key(|c| (c.x, c.y, c.z));
+        rng.shuffle(&mut candidates);
+        let mut cavities = 0;
+        for c in candidates {
+            if cavities == params.get("cavities") {
+                break;
+            }
+            if solid(&drops, c) {
+                drops.remove(&c);
+                for d in DIRS {
+                    if rng.chance(50) {
+                        drops.remove(&(c + d));
+                    }
+                }
+                cavities += 1;
+            }
+        }
+
+        let mut drops: Vec<Coord> = drops.into_iter().collect();
+        drops.sort_by_key(|c| (c.x, c.y, c.z));
+        rng.shuffle(&mut drops);
+        drops.iter().map(|c| format!["{},{},{}\n", c.x, c.y, c.z]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_18_test() {
+        let mut set = HashSet::from([Coord::new(1,1,1), Coord::new(2,1,1)]);
+        assert_eq!(
+            sides_starting_at_first(&mut set, false),
+            (10, HashSet::from([Coord::new(1,1,1), Coord::new(2,1,1)]))
+        );
+        assert_eq!(set, HashSet::new());
+    }
+
+    #[test]
+    fn air() {
+        let set = HashSet::from_iter([Coord::new(1,1,1), Coord::new(2,1,1)]);
+        assert_eq!(
+            fill_volume((Coord::new(0,0,0), Coord::new(3,2,2)), &set),
+            HashSet::from([
+                Coord { x: 0, y: 2, z: 0 },
+                Coord { x: 2, y: 2, z: 2 },
+                Coord { x: 2, y: 1, z: 0 },
+                Coord { x: 2, y: 0, z: 2 },
+                Coord { x: 3, y: 2, z: 0 },
+                Coord { x: 3, y: 0, z: 1 },
+                Coord { x: 1, y: 2, z: 0 },
+                Coord { x: 0, y: 1, z: 0 },
+                Coord { x: 2, y: 1, z: 2 },
+                Coord { x: 1, y: 1, z: 2 },
+                Coord { x: 0, y: 2, z: 1 },
+                Coord { x: 1, y: 0, z: 0 },
+                Coord { x: 3, y: 0, z: 0 },
+                Coord { x: 0, y: 2, z: 2 },
+                Coord { x: 3, y: 1, z: 0 },
+                Coord { x: 2, y: 2, z: 1 },
+                Coord { x: 1, y: 0, z: 2 },
+                Coord { x: 3, y: 1, z: 1 },
+                Coord { x: 1, y: 2, z: 2 },
+                Coord { x: 3, y: 2, z: 2 },
+                Coord { x: 2, y: 0, z: 0 },
+                Coord { x: 0, y: 0, z: 2 },
+                Coord { x: 0, y: 1, z: 2 },
+                Coord { x: 0, y: 0, z: 0 },
+                Coord { x: 3, y: 1, z: 2 },
+                Coord { x: 0, y: 0, z: 1 },
+                Coord { x: 2, y: 0, z: 1 },
+                Coord { x: 1, y: 0, z: 1 },
+                Coord { x: 3, y: 0, z: 2 },
+                Coord { x: 3, y: 2, z: 1 },
+                Coord { x: 1, y: 1, z: 0 },
+                Coord { x: 1, y: 2, z: 1 },
+                Coord { x: 0, y: 1, z: 1 },
+                Coord { x: 2, y: 2, z: 0 }
+            ])
+        );
+    }
+
+    // Every side of every cube, minus the two sides of each pair that touch.
+    #[test]
+    fn generated_droplets() {
+        for seed in 0..5 {
+            let input = utils::generate::generate::<Day18>(seed, &["size=12".to_string()]).unwrap();
+            let drops = Day18::parse(&Input::from(input.as_str())).unwrap();
+            let touching = drops.iter().flat_map(|&c| DIRS.map(|d| c + d)).filter(|c| drops.contains(c)).count();
+            assert_eq!(Day18::part1(&drops), 6 * drops.len() as u32 - touching as u32, "seed {seed}");
+            assert!(Day18::part2(&drops) < Day18::part1(&drops), "seed {seed} should have cavities");
+        }
+    }
+
+    #[test]
+    fn generated_params_in_range() {
+        let generate = |params: &str| utils::generate::generate::<Day18>(3, &params.split_whitespace().map(String::from).collect::<Vec<_>>());
+        assert!(generate("size=1000").unwrap_err().starts_with("size has to be from 1 to 100, not 1000\n"));
+        assert!(generate("size=0").is_err());
+        assert!(generate(&format!["blobs={}", u64::MAX]).is_err());
+        assert!(generate("cavities=101").is_err());
+
+        let drops = Day18::parse(&Input::from(generate("size=100 blobs=2 cavities=100").unwrap().as_str())).unwrap();
+        assert!(drops.iter().all(|c| [c.x, c.y, c.z].iter().all(|&v| (0..MAX_SIZE as i32).contains(&v))));
+    }
+
+    #[test]
+    fn dfs_out() {
+        let set = HashSet::from_iter([Coord::new(1,1,1), Coord::new(2,1,1)]);
+        assert_eq![can_dfs_out(Coord::new(0,1,1), &set), (true, vec![Coord::new(0,1,1), Coord::new(-1,1,1)])];
+    }
+
+    #[test]
+    fn dfs_out_complex() {
+        let set = HashSet::from_iter([Coord::new(1,1,1), Coord::new(2,1,1), Coord::new(1,2,1)]);
+        assert_eq![can_dfs_out(Coord::new(2,2,1), &set), (true, vec![Coord::new(2,2,1), Coord::new(2,2,0), Coord::new(2,2,-1)])];
+    }
+
+    #[test]
+    fn cant_dfs_out_from_hole() {
+        // Hole @ 1,1,1
+        let set = HashSet::from_iter([
+            Coord::new(0,1,1),
+            Coord::new(2,1,1),
+            Coord::new(1,0,1),
+            Coord::new(1,2,1),
+            Coord::new(1,1,2),
+            Coord::new(1,1,0)
+        ]);
+        assert_eq![
+            can_dfs_out(Coord::new(1,1,1), &set),
+            (false, vec![Coord::new(1,1,1)])
+        ];
+    }
+
+    #[test]
+    fn cant_dfs_out_from_larger_hole() {
+        // Hole @ 1,1,1
+        let set = HashSet::from_iter([
+            Coord::new(0,1,1),
+            Coord::new(3,1,1),
+            Coord::new(1,0,1),
+            Coord::new(1,2,1),
+            Coord::new(2,0,1),
+            Coord::new(2,2,1),
+            Coord::new(1,1,2),
+            Coord::new(1,1,0),
+            Coord::new(2,1,2),
+            Coord::new(2,1,0)
+        ]);
+        assert_eq![
+            can_dfs_out(Coord::new(1,1,1), &set),
+            (false, vec![Coord::new(1,1,1), Coord::new(2,1,1)])
+        ];
+    }
+
+    #[test]
+    fn dfs_out_from_larger_hole_top_missing() {
+        // Hole @ 1,1,1
+        let set = HashSet::from_iter([
+            Coord::new(0,1,1),
+            Coord::new(3,1,1),
+            Coord::new(1,0,1),
+            Coord::new(1,2,1),
+            Coord::new(2,0,1),
+            Coord::new(2,2,1),
+            Coord::new(1,1,2),
+            Coord::new(1,1,0),
+            Coord::new(2,1,0)
+        ]);
+        assert_eq![
+            can_dfs_out(Coord::new(1,1,1), &set),
+            (true, vec![Coord::new(1,1,1), Coord::new(2,1,1), Coord::new(2,1,2), Coord::new(2,1,3), Coord::new(2,1,4)])
+        ];
+    }
+}
\ No newline at end of file
@@ -0,0 +1,779 @@
+use std::collections::HashMap;
+
+use scan_fmt::scan_fmt;
+use scan_fmt::parse::ScanError;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::generate::{Generate, Param, Params, Rng};
+use utils::memo::Memo;
+use utils::parallel;
+use utils::solution::Solution;
+use tracing::{debug, info, trace};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Vec<Blueprint>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        parse_input(input)
+    }
+
+    // Sum of quality levels.
+    fn part1(blueprints: &Self::Input) -> u32 {
+        part1(blueprints)
+    }
+
+    // Product of max geodes.
+    fn part2(blueprints: &Self::Input) -> u32 {
+        part2(blueprints)
+    }
+}
+
+#[cfg(test)]
+fn parse(path: &str) -> Vec<Blueprint> {
+    parse_input(&Input::load(path).unwrap()).unwrap()
+}
+
+fn parse_input(input: &Input) -> Result<Vec<Blueprint>, InputError> {
+    // Parse input
+    input.parse_lines(Blueprint::new)
+}
+
+fn part1(blueprints: &Vec<Blueprint>) -> u32 {
+    let quality_levels: Vec<u32> = parallel::map(blueprints, quality_level);
+    let quality_level_sum: u32 = quality_levels.iter().sum();
+    return quality_level_sum;
+}
+
+fn part2(blueprints: &Vec<Blueprint>) -> u32 {
+    let maxes: Vec<u32> = parallel::map(&blueprints[..blueprints.len().min(3)], max_geodes_for_blueprint);
+    let max_product: u32 = maxes.iter().product();
+    return max_product;
+}
+
+fn max_geodes_for_blueprint(blueprint: &Blueprint) -> u32 {
+    let fac = Factory::new();
+    const MINUTES: u32 = 32;
+
+    let mut best_so_far = 0;
+    let mut seen = Seen::new();
+    let max_geodes = max_geodes(blueprint, fac, MINUTES, &mut best_so_far, &mut seen);
+    debug!(blueprint = blueprint.id, "{}", seen.stats());
+    info!(blueprint = blueprint.id, max_geodes, "In {MINUTES} minutes");
+    return max_geodes;
+}
+
+fn quality_level(blueprint: &Blueprint) -> u32 {
+    let fac = Factory::new();
+    const MINUTES: u32 = 24;
+
+    let mut best_so_far = 0;
+    let mut seen = Seen::new();
+    let max_geodes = max_geodes(blueprint, fac, MINUTES, &mut best_so_far, &mut seen);
+    debug!(blueprint = blueprint.id, "{}", seen.stats());
+    info!(blueprint = blueprint.id, max_geodes, "In {MINUTES} minutes");
+    return max_geodes * blueprint.id;
+}
+
+// Everything the rest of a search depends on besides the geodes collected so far. Resources are
+// capped at the most that could be spent in the minutes left: with at least that much, every robot
+// that needs them is always affordable, so having more can't change anything.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    robots: [u8; 4],
+    resources: [u16; 3],
+    building: Option<Robot>,
+    minutes_remaining: u8
+}
+
+impl State {
+    fn new(blueprint: &Blueprint, factory: &Factory, minutes_remaining: u32) -> Self {
+        let cap = |amount: u32, robot: Robot| amount.min(blueprint.max_robots(robot) * minutes_remaining) as u16;
+        State {
+            robots: [factory.ore_robots, factory.clay_robots, factory.obsidian_robots, factory.geode_robots].map(|n| n as u8),
+            resources: [cap(factory.ore, Robot::Ore), cap(factory.clay, Robot::Clay), cap(factory.obsidian, Robot::Obsidian)],
+            building: factory.robot_in_progress,
+            minutes_remaining: minutes_remaining as u8
+        }
+    }
+}
+
+// The most geodes each state has been reached with. What's left to collect from a state doesn't
+// depend on how it got there, so getting there again with no more geodes can't do any better.
+type Seen = Memo<State, u32>;
+
+fn max_geodes(blueprint: &Blueprint, mut factory: Factory, minutes_remaining: u32, best_so_far: &mut u32, seen: &mut Seen) -> u32 {
+    if minutes_remaining == 0 {
+        if factory.geodes > 0 {
+            trace!(?factory, "Out of time");
+        }
+        *best_so_far = std::cmp::max(*best_so_far, factory.geodes);
+        return factory.geodes;
+    }
+
+    if *best_so_far > production_bound(blueprint, &factory, minutes_remaining) {
+        return 0; // Prune this branch; it's not good enough.
+    }
+
+    let state = State::new(blueprint, &factory, minutes_remaining);
+    if seen.get(&state).is_some_and(|geodes| geodes >= factory.geodes) {
+        return 0; // Prune here too; this state has already been searched with at least as many geodes.
+    }
+    seen.insert(state, factory.geodes);
+
+    // Run a produce step.
+    factory.produce();
+
+    let mut possible_max_geodes = vec![];
+
+    // Try each world where we start building a robot now, if it's possible.
+    for robotype in Robot::iter().rev() {
+        if factory.can_build(blueprint, robotype) {
+            let mut next_fac = factory.clone();
+            next_fac.start_build(blueprint, robotype);
+    
+            let max_next = max_geodes(blueprint, next_fac, minutes_remaining - 1, best_so_far, seen);
+            possible_max_geodes.push(max_next);
+        }
+    }
+
+    // Also try *not* building a robot now.
+    let max_no_new_robot = max_geodes(blueprint, factory.clone(), minutes_remaining - 1, best_so_far, seen);
+    possible_max_geodes.push(max_no_new_robot);
+
+    let max = *possible_max_geodes.iter().max().unwrap();
+    *best_so_far = std::cmp::max(max, *best_so_far);
+    return max;
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Blueprint {
+    id: u32,
+    ore_robot_ore: u32,
+    clay_robot_ore: u32,
+    obsidian_robot_ore: u32,
+    obsidian_robot_clay: u32,
+    geode_robot_ore: u32,
+    geode_robot_obsidian: u32
+}
+
+impl Blueprint {
+    fn new(input: &str) -> Result<Self, ScanError> {
+        const FMT: &str = "Blueprint {d}: Each ore robot costs {d} ore. Each clay robot costs {d} ore. Each obsidian robot costs {d} ore and {d} clay. Each geode robot costs {d} ore and {d} obsidian.";
+        let parsed = scan_fmt![input, &FMT, u32, u32, u32, u32, u32, u32, u32]?;
+
+        Ok(Self {
+            id: parsed.0,
+            ore_robot_ore: parsed.1,
+            clay_robot_ore: parsed.2,
+            obsidian_robot_ore: parsed.3,
+            obsidian_robot_clay: parsed.4,
+            geode_robot_ore: parsed.5,
+            geode_robot_obsidian: parsed.6
+        })
+    }
+
+    fn max_robots(&self, robotype: Robot) -> u32 {
+        use std::cmp::max;
+        let max_orebots = max(self.ore_robot_ore, max(self.obsidian_robot_ore, max(self.clay_robot_ore, self.geode_robot_ore)));
+        let max_claybots = self.obsidian_robot_clay;
+        let max_obsbots = self.geode_robot_obsidian;
+        match robotype {
+            Robot::Ore => max_orebots,
+            Robot::Clay => max_claybots,
+            Robot::Obsidian => max_obsbots,
+            Robot::Geode => u32::MAX
+        }
+    }
+
+    fn all_plans(&self) -> Vec<Plan> {
+        use Robot::*;
+        use std::cmp::max;
+        // Make all plans where the sum of robots is less than the time limit, halving  max resources needed until you get to 1s.
+        let max_orebots = max(self.ore_robot_ore, max(self.obsidian_robot_ore, max(self.clay_robot_ore, self.geode_robot_ore)));
+        let max_claybots = self.obsidian_robot_clay;
+        let max_obsbots = self.geode_robot_obsidian;
+        let mut plan = <Plan as NewPlan>::new(max_orebots, max_claybots, max_obsbots, 1);
+        let mut plans = vec![];
+
+        while plan[&Ore] > 1 && plan[&Clay] > 1 && plan[&Obsidian] > 1 {
+            plans.push(plan.clone());
+
+            let max_val = max(plan[&Ore], max(plan[&Clay], plan[&Obsidian]));
+            let reduced_ore = plan[&Ore].div_ceil(2);
+            let reduced_clay = plan[&Clay].div_ceil(2);
+            let reduced_obs = plan[&Obsidian].div_ceil(2);
+
+            let mut drop_ore = plan.clone();
+            drop_ore.insert(Ore, reduced_ore);
+
+            let mut drop_clay = plan.clone();
+            drop_clay.insert(Clay, reduced_clay);
+
+            let mut drop_obs = plan.clone();
+            drop_obs.insert(Obsidian, reduced_obs);
+
+            plan = match (plan[&Ore], plan[&Clay], plan[&Obsidian]) {
+                (r, c, o) if r == c && r == o => {
+                    plans.push(drop_ore);
+                    plans.push(drop_clay);
+                    plans.push(drop_obs);
+                    <Plan as NewPlan>::new(reduced_ore, reduced_clay, reduced_obs, plan[&Geode])
+                },
+                (r, c, o) if r == max_val && c == max_val => {
+                    plans.push(drop_ore);
+                    plans.push(drop_clay);
+                    <Plan as NewPlan>::new(reduced_ore, reduced_clay, plan[&Obsidian], plan[&Geode])
+                },
+                (r, c, o) if c == max_val && o == max_val => {
+                    plans.push(drop_clay);
+                    plans.push(drop_obs);
+                    <Plan as NewPlan>::new(plan[&Ore], reduced_clay, reduced_obs, plan[&Geode])
+                },
+                (r, c, o) if r == max_val && o == max_val => {
+                    plans.push(drop_ore);
+                    plans.push(drop_obs);
+                    <Plan as NewPlan>::new(reduced_ore, plan[&Clay], reduced_obs, plan[&Geode])
+                },
+                (r, c, o) if r == max_val => {
+                    plans.push(drop_ore);
+                    <Plan as NewPlan>::new(reduced_ore, plan[&Clay], plan[&Obsidian], plan[&Geode])
+                },
+                (r, c, o) if c == max_val => {
+                    plans.push(drop_clay);
+                    <Plan as NewPlan>::new(plan[&Ore], reduced_clay, plan[&Obsidian], plan[&Geode])
+                },
+                (r, c, o) if o == max_val => {
+                    plans.push(drop_obs);
+                    <Plan as NewPlan>::new(plan[&Ore], plan[&Clay], reduced_obs, plan[&Geode])
+                },
+                (_, _, _) => panic!("Should have matched one of the others!")
+            };
+        }
+
+        plans.push(plan.clone());
+        return plans;
+    }
+}
+
+///
+/// Using this Blueprint, determine an upper bound on the number of geodes that could be produced
+/// by a given factory.
+/// For this upper bound, let's try assuming that we can build robots with the same pool of resources. Just calculate
+/// the maximum amount of resources producible of a given type and max out the robots that cost up to that amount.
+/// 
+fn production_bound(blueprint: &Blueprint, factory: &Factory, time_remaining: u32) -> u32 {
+    // Simple production bound: assume we can build a geode robot every minute remaining and add up all the geodes possible.
+    let geode_production_time = time_remaining;
+    let geodes_if_one_bot_built_every_minute = geode_production_time * (geode_production_time + 1) / 2;
+    let possible_geodes = factory.geodes + factory.geode_robots * geode_production_time + geodes_if_one_bot_built_every_minute;
+
+    return possible_geodes;
+
+
+    // Pretend the max ore we can produce is what we'd produce if we spent all our ore on ore robots.
+    // If time_remaining is 16 and an ore robot costs 4 ore, starting w/ 1 robot...
+    // 123456789012       3456   
+    // 111111111111       1111   =16    
+    //     11111111       1111   +12
+    //         1111       1111   +8
+    //         1111       1111   +8
+    //                    1111   +4
+    //                    1111   +4
+    //                    1111   +4
+    //                    1111   +4
+
+    // = 1 * time_remaining + 1 * (time_remaining - rate_ore) + 2 * (remaining - 2*rate_ore) + 4 * (time_remaining - 3*rate_ore)
+
+    // = 2^0 * 4 + 2^1 * 4 + 2^2 * 4 + 2^3 * 4 = 4 * (2^0 + 2^1 + 2^2 + 2^3) = 4 * (2^4 - 1)
+    // The number of exponents is time_remaining / cost. That's the doubling rate.
+    let ore_cost = blueprint.ore_robot_ore;
+    let ore_robot_double_rate = time_remaining / ore_cost;
+    let max_ore_possible = ore_cost * ((1 << ore_robot_double_rate) - 1);
+
+    // Pretend the max clay we can produce is what we'd produce if we spent all the ore we had producing clay robots instantly.
+    let max_clay_possible = max_ore_possible / blueprint.clay_robot_ore;
+
+    // Pretend the max obsidian we can produce is what we'd produce if we spent all the clay and ore we could possibly have instantly
+    let max_obsidian_possible = std::cmp::min(max_clay_possible / blueprint.obsidian_robot_clay, max_ore_possible / blueprint.obsidian_robot_ore);
+
+    // Pretend the max geode robots we can produce is what we'd produce if we spent all the obsidian and ore we could possibly have instantly.
+    let max_geodes_possible = std::cmp::min(max_obsidian_possible / blueprint.geode_robot_obsidian, max_ore_possible / blueprint.geode_robot_ore);
+
+    // Pretend we can produce that many geodes every remaining minute.
+    return max_geodes_possible * time_remaining;
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
+enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Factory {
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geodes: u32,
+    robot_in_progress: Option<Robot>
+}
+
+impl Factory {
+    fn new() -> Self {
+        Self {
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+            robot_in_progress: None
+        }
+    }
+
+    fn can_build(&self, blueprint: &Blueprint, robot: Robot) -> bool {
+        if self.robot_in_progress.is_some() {
+            return false;
+        }
+
+        if self.robot_quantity(robot) >= blueprint.max_robots(robot) {
+            return false;
+        }
+
+        match robot {
+            Robot::Geode => self.ore >= blueprint.geode_robot_ore && self.obsidian >= blueprint.geode_robot_obsidian,
+            Robot::Obsidian => self.ore >= blueprint.obsidian_robot_ore && self.clay >= blueprint.obsidian_robot_clay,
+            Robot::Clay => self.ore >= blueprint.clay_robot_ore,
+            Robot::Ore => self.ore >= blueprint.ore_robot_ore
+        }
+    }
+
+    fn start_build(&mut self, blueprint: &Blueprint, robot: Robot) {
+        match robot {
+            Robot::Ore => {
+                self.ore -= blueprint.ore_robot_ore;
+                self.robot_in_progress = Some(robot);
+            },
+            Robot::Clay => {
+                self.ore -= blueprint.clay_robot_ore;
+                self.robot_in_progress = Some(robot);
+            },
+            Robot::Obsidian => {
+                self.ore -= blueprint.obsidian_robot_ore;
+                self.clay -= blueprint.obsidian_robot_clay;
+                self.robot_in_progress = Some(robot);
+            },
+            Robot::Geode => {
+                self.ore -= blueprint.geode_robot_ore;
+                self.obsidian -= blueprint.geode_robot_obsidian;
+                self.robot_in_progress = Some(robot);
+            }
+        }
+    }
+
+    fn robot_quantity(&self, robot_type: Robot) -> u32 {
+        match robot_type {
+            Robot::Ore => self.ore_robots,
+            Robot::Clay => self.clay_robots,
+            Robot::Obsidian => self.obsidian_robots,
+            Robot::Geode => self.geode_robots
+        }
+    }
+
+    fn produce(&mut self) {
+        self.ore += self.ore_robots;
+        self.clay += self.clay_robots;
+        self.obsidian += self.obsidian_robots;
+        self.geodes += self.geode_robots;
+
+        use Robot::*;
+        match self.robot_in_progress {
+            None => (),
+            Some(Ore) => self.ore_robots += 1,
+            Some(Obsidian) => self.obsidian_robots += 1,
+            Some(Clay) => self.clay_robots += 1,
+            Some(Geode) => self.geode_robots += 1,
+        }
+        self.robot_in_progress = None;
+    }
+}
+
+type Plan = HashMap<Robot, u32>;
+
+trait NewPlan {
+    fn new(orebots: u32, claybot: u32, obsbots: u32, geodebots: u32) -> Plan;
+}
+
+impl NewPlan for Plan {
+    fn new(orebots: u32, claybot: u32, obsbots: u32, geodebots: u32) -> Plan {
+        use Robot::*;
+        Plan::from([(Ore, orebots), (Clay, claybot), (Obsidian, obsbots), (Geode, geodebots)])
+    }
+}
+
+fn time_to_nth_geodebot(plan: &Plan, mut factory: Factory, blueprint: &Blueprint, n: u32, accum: u32, mut min_so_far: &mut u32) -> Option<u32> {
+    if *min_so_far < accum {
+        return None; // Prune branch
+    }
+
+    if factory.geode_robots >= n {
+        *min_so_far = std::cmp::min(accum, *min_so_far);
+        return Some(accum);
+    }
+
+    // It might make sense to always build Clay, then Obsidian, then Geode, then Ore?
+    // For now, try to build Geode first, then Obsidian, then Clay, then Ore.
+    // Actually, we'll try building any of the robots we still need to build, if possible.
+    let mut possibilities = vec![];
+    
+    factory.produce();
+
+    let no_robot = time_to_nth_geodebot(plan, factory.clone(), blueprint, n, accum + 1, min_so_far);
+    if let Some(time_no_robot) = no_robot {
+        possibilities.push(time_no_robot);
+    } 
+
+    for robotype in Robot::iter().rev() {
+        if plan[&robotype] > factory.robot_quantity(robotype) && factory.can_build(blueprint, robotype) {
+            let mut next_fac = factory.clone();
+            next_fac.start_build(blueprint, robotype);
+            let next = time_to_nth_geodebot(plan, next_fac, blueprint, n, accum + 1, min_so_far);
+            if let Some(time_next) = next {
+                possibilities.push(time_next);
+            }
+        }
+    }
+
+    return possibilities.into_iter().min();
+}
+
+fn min_time_to_first_geodebot(factory: Factory, blueprint: &Blueprint, time_limit: u32) -> Option<u32> {
+    let plans = blueprint.all_plans();
+    let mut min_so_far = time_limit;
+    let mut times = vec![];
+    for plan in plans.iter().rev() {
+        let next_fac = factory.clone();
+        if let Some(time) = time_to_nth_geodebot(plan, next_fac, blueprint, 1, 0 /*accum*/, &mut min_so_far) {
+            times.push(time);
+        }
+    }
+    // let times: Vec<u32> = plans.into_iter().filter_map(|plan| time_to_nth_geodebot(&plan, factory.clone(), blueprint, 1, 0 /* accum */, &mut min_so_far)).collect();
+    let min_time = times.iter().min();
+    return min_time.copied();
+}
+
+impl Generate for Day19 {
+    const PARAMS: &'static [Param] = &[
+        Param { name: "blueprints", default: 30, help: "how many blueprints" },
+        Param { name: "min_ore", default: 2, help: "the least ore any robot costs" },
+        Param { name: "max_ore", default: 4, help: "the most ore any robot costs" },
+        Param { name: "min_clay", default: 5, help: "the least clay an obsidian robot costs" },
+        Param { name: "max_clay", default: 20, help: "the most clay an obsidian robot costs" },
+        Param { name: "min_obsidian", default: 7, help: "the least obsidian a geode robot costs" },
+        Param { name: "max_obsidian", default: 20, help: "the most obsidian a geode robot costs" }
+    ];
+
+    fn generate(rng: &mut Rng, params: &Params) -> String {
+        // Every robot costs at least one of something, or there's no decision to make.
+        let mut cost = |resource: &str| {
+            let min = params.get(&format!["min_{resource}"]).max(1);
+            rng.range(min..=params.get(&format!["max_{resource}"]).max(min))
+        };
+        (1..=params.get("blueprints")).map(|id| format![
+            "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+            cost("ore"), cost("ore"), cost("ore"), cost("clay"), cost("ore"), cost("obsidian")
+        ]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upper_bound_test() {
+        // The sample for blueprint 1 should state its upper bound is *at least* 9 geodes in 24 minutes, since that's the true max it can produce.
+        let blueprint_1 = Blueprint::new("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.").unwrap();
+        let fac = Factory::new();
+        assert![production_bound(&blueprint_1, &fac, 24) > 9];
+
+        // The sample for blueprint 1 should state its upper bound is *at least* 9 geodes in 24 minutes, since that's the true max it can produce.
+        let blueprint_2 = Blueprint::new("Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.").unwrap();
+        assert![production_bound(&blueprint_2, &fac, 24) > 12];
+    }
+
+    #[test]
+    fn factory() {
+        let mut default = Factory::new();
+        default.produce();
+        default.produce();
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 0];
+        assert_eq![default.obsidian_robots, 0];
+        assert_eq![default.geode_robots, 0];
+        assert_eq![default.ore, 3];
+        assert_eq![default.clay, 0];
+        assert_eq![default.obsidian, 0];
+        assert_eq![default.geodes, 0];
+        assert_eq![default.robot_in_progress, None];
+    }
+
+    #[test]
+    fn factory_production_all() {
+        let mut default = Factory {
+            ore_robots: 29,
+            clay_robots: 23,
+            obsidian_robots: 19,
+            geode_robots: 7,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+            robot_in_progress: None
+        };
+        default.produce();
+        default.produce();
+
+        assert_eq![default.ore_robots, 29];
+        assert_eq![default.clay_robots, 23];
+        assert_eq![default.obsidian_robots, 19];
+        assert_eq![default.geode_robots, 7];
+        assert_eq![default.ore, 58];
+        assert_eq![default.clay, 46];
+        assert_eq![default.obsidian, 38];
+        assert_eq![default.geodes, 14];
+        assert_eq![default.robot_in_progress, None];
+    }
+
+    #[test]
+    fn factory_production_sample() {
+        let blueprint = Blueprint::new("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.").unwrap();
+        let mut default = Factory::new();
+
+        // Minute 1
+        default.produce();
+        assert_eq![default.ore, 1];
+
+        // Minute 2
+        default.produce();
+        assert_eq![default.ore, 2];
+
+        // Minute 3
+        assert![default.can_build(&blueprint, Robot::Clay)];
+        default.start_build(&blueprint, Robot::Clay);
+
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 1];
+        assert_eq![default.obsidian_robots, 0];
+        assert_eq![default.geode_robots, 0];
+        assert_eq![default.ore, 1];
+        assert_eq![default.clay, 0];
+        assert_eq![default.obsidian, 0];
+        assert_eq![default.geodes, 0];
+        assert_eq![default.robot_in_progress, None];
+
+        // Minute 4
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 1];
+        assert_eq![default.ore, 2];
+        assert_eq![default.clay, 1];
+
+        // Minute 5
+        assert![default.can_build(&blueprint, Robot::Clay)];
+        default.start_build(&blueprint, Robot::Clay);
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 2];
+        assert_eq![default.ore, 1];
+        assert_eq![default.clay, 2];
+
+        // Minute 6
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 2];
+        assert_eq![default.ore, 2];
+        assert_eq![default.clay, 4];
+
+        // Minute 7
+        assert![default.can_build(&blueprint, Robot::Clay)];
+        default.start_build(&blueprint, Robot::Clay);
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 3];
+        assert_eq![default.ore, 1];
+        assert_eq![default.clay, 6];
+
+        // Minute 8
+        default.produce();
+
+        assert_eq![default.ore, 2];
+        assert_eq![default.clay, 9];
+
+        // Minute 9
+        default.produce();
+
+        assert_eq![default.ore, 3];
+        assert_eq![default.clay, 12];
+
+        // Minute 10
+        default.produce();
+
+        assert_eq![default.ore, 4];
+        assert_eq![default.clay, 15];
+
+        // Minute 11
+        assert![default.can_build(&blueprint, Robot::Obsidian)];
+        default.start_build(&blueprint, Robot::Obsidian);
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 3];
+        assert_eq![default.obsidian_robots, 1];
+        assert_eq![default.geode_robots, 0];
+        assert_eq![default.ore, 2];
+        assert_eq![default.clay, 4];
+        assert_eq![default.obsidian, 0];
+    }
+
+    #[test]
+    fn factory_production_minute20() {
+        let blueprint = Blueprint::new("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.").unwrap();
+        let mut default = Factory {
+            ore_robots: 1,
+            clay_robots: 4,
+            obsidian_robots: 2,
+            geode_robots: 1,
+            ore: 4,
+            clay: 25,
+            obsidian: 7,
+            geodes: 2,
+            robot_in_progress: None
+        };
+
+        // Minute 21
+        assert![default.can_build(&blueprint, Robot::Geode)];
+        default.start_build(&blueprint, Robot::Geode);
+        default.produce();
+
+        assert_eq![default.ore_robots, 1];
+        assert_eq![default.clay_robots, 4];
+        assert_eq![default.obsidian_robots, 2];
+        assert_eq![default.geode_robots, 2];
+        assert_eq![default.ore, 3];
+        assert_eq![default.clay, 29];
+        assert_eq![default.obsidian, 2];
+        assert_eq![default.geodes, 3];
+
+        default.produce();
+        default.produce();
+        default.produce();
+
+        assert_eq![default.ore, 6];
+        assert_eq![default.clay, 41];
+        assert_eq![default.obsidian, 8];
+        assert_eq![default.geodes, 9];
+    }
+
+    #[test]
+    fn first_geode_test() {
+        use Robot::*;
+        let blueprint_1 = Blueprint::new("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.").unwrap();
+        let fac = Factory::new();
+        let plan = Plan::from([
+            (Ore, 1),
+            (Clay, 1),
+            (Obsidian, 1),
+            (Geode, 1)
+        ]);
+
+        let mut min = 26;
+        assert_eq!(time_to_nth_geodebot(&plan, fac, &blueprint_1, 1, 0, &mut min), Some(26));
+        min = 25;
+        assert_eq!(time_to_nth_geodebot(&plan, fac, &blueprint_1, 1, 0, &mut min), None);
+
+        let fac = Factory::new();
+        let plan = Plan::from([
+            (Ore, 1),
+            (Clay, 2),
+            (Obsidian, 1),
+            (Geode, 1)
+        ]);
+
+        min = 25;
+        assert_eq!(time_to_nth_geodebot(&plan, fac, &blueprint_1, 1, 0, &mut min), Some(20));
+
+        let min_to_first_geode = min_time_to_first_geodebot(Factory::new(), &blueprint_1, 24).unwrap();
+        assert!(min_to_first_geode < 20);
+        assert_eq!(min_to_first_geode, 18);
+    }
+
+    // #[test]
+    // fn first_geode_on_inputs() {
+    //     let sample_blueprints = parse("sample.txt");
+        
+    //     assert!(min_time_to_first_geodebot(Factory::new(), &sample_blueprints[0], 24).unwrap() < 24);
+    //     // assert!(min_time_to_first_geodebot(Factory::new(), &sample_blueprints[1], 24).unwrap() < 24);
+
+    //     // let input_blueprints = parse("input.txt");
+    //     // for blueprint in input_blueprints {
+    //     //     let result = min_time_to_first_geodebot(Factory::new(), &blueprint, 24);
+    //     //     if result.is_none() {
+    //     //         println!("Didn't find a min time to first geode for input blueprint {}", blueprint.id);
+    //     //     }
+    //     //     assert!(result.unwrap() < 25);
+    //     // }
+    // }
+
+    #[test]
+    fn generated_blueprints() {
+        let args = ["blueprints=2", "min_ore=3", "max_ore=3", "max_clay=8", "max_obsidian=12"].map(String::from);
+        let input = utils::generate::generate::<Day19>(19, &args).unwrap();
+        let blueprints = parse_input(&Input::from(input.as_str())).unwrap();
+        assert_eq!(blueprints.iter().map(|b| b.id).collect::<Vec<_>>(), [1, 2]);
+        assert!(blueprints.iter().all(|b| b.ore_robot_ore == 3 && (5..=8).contains(&b.obsidian_robot_clay)));
+        assert!(part1(&blueprints) > 0);
+    }
+
+    #[test]
+    fn part1_sample() {
+        let blueprints = parse("sample.txt");
+        assert_eq!(part1(&blueprints), 33);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let blueprints = parse("sample.txt");
+        assert_eq!(part2(&blueprints), 3472);
+    }
+
+    // #[test]
+    // fn part1_input() {
+    //     let blueprints = parse("input.txt");
+    //     assert_eq!(part1(&blueprints), 1349);
+    // }
+}
\ No newline at end of file
@@ -0,0 +1,518 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use core::str::FromStr;
+use scan_fmt::scan_fmt;
+use utils::error::{InputError, LineError};
+use utils::input::Input;
+use utils::generate::{Generate, Param, Params, Rng};
+use utils::solution::Solution;
+use utils::validate::{Assumption, Validate};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Optree;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        parse_lines(input.lines())
+    }
+
+    // What root yells.
+    fn part1(optree: &Self::Input) -> u64 {
+        evaluate(make_expr("root", optree)).unwrap()
+    }
+
+    // What I should yell.
+    fn part2(optree: &Self::Input) -> u64 {
+        find_x(optree)
+    }
+}
+
+fn parse_lines<'a>(lineiter: impl Iterator<Item = &'a str>) -> Result<Optree, InputError> {
+    lineiter.enumerate().map(|(i, s)| parse_line(&s).map_err(|err| err.on_line(i + 1, s))).collect()
+}
+
+fn parse_line(line: &str) -> Result<(Monkey, Op), InputError> {
+    let (monkey, op) = line.split_once(": ").ok_or("Expected \"monkey: job\"")?;
+    return Ok((monkey.to_string(), op.parse()?));
+}
+
+fn evaluate(expression: Expr) -> Option<u64> {
+    let reduced = reduce(&expression);
+    match reduced {
+        Expr::Val(val) => Some(val),
+        _ => None
+    }
+}
+
+fn make_expr(symbol: &str, optree: &Optree) -> Expr {
+    match &optree[symbol] {
+        Op::Val(val) => Expr::Val(*val),
+        Op::Mul(m1, m2) => Expr::Mul(Box::new(make_expr(&m1, optree)), Box::new(make_expr(&m2, optree))),
+        Op::Div(m1, m2) => Expr::Div(Box::new(make_expr(&m1, optree)), Box::new(make_expr(&m2, optree))),
+        Op::Add(m1, m2) => Expr::Add(Box::new(make_expr(&m1, optree)), Box::new(make_expr(&m2, optree))),
+        Op::Sub(m1, m2) => Expr::Sub(Box::new(make_expr(&m1, optree)), Box::new(make_expr(&m2, optree)))
+    }
+}
+
+fn is_val(expression: &Expr) -> bool {
+    match expression {
+        Expr::Val(_) => true,
+        _ => false
+    }
+}
+
+fn is_x(expression: &Expr) -> bool {
+    match expression {
+        Expr::X => true,
+        _ => false
+    }
+}
+
+fn reduce(expression: &Expr) -> Expr {
+    use Expr::*;
+    if let Val(v) = expression { return Val(*v); }
+    if let X = expression { return X;}
+
+    let (lhs, rhs) = match expression {
+        Mul(l, r) => (l, r),
+        Div(l, r) => (l, r),
+        Add(l, r) => (l, r),
+        Sub(l, r) => (l, r),
+        _ => panic!("Impossible")
+    };
+    let lhs = reduce(lhs);
+    let rhs = reduce(rhs);
+
+    match (lhs, rhs) {
+        (Val(l), Val(r)) => match expression {
+            Mul(_, _) => Val(l * r),
+            Div(_, _) => Val(l / r),
+            Add(_, _) => Val(l + r),
+            Sub(_, _) => Val(l - r),
+            _ => panic!("Not possible")
+        },
+        (Val(l), expr_r) => match expression {
+            Mul(_, _) => Mul(Box::new(Val(l)), Box::new(expr_r)),
+            Div(_, _) => Div(Box::new(Val(l)), Box::new(expr_r)),
+            Add(_, _) => Add(Box::new(Val(l)), Box::new(expr_r)),
+            Sub(_, _) => Sub(Box::new(Val(l)), Box::new(expr_r)),
+            _ => panic!("Not possible")
+        },
+        (expr_l, Val(r)) => match expression {
+            Mul(_, _) => Mul(Box::new(expr_l), Box::new(Val(r))),
+            Div(_, _) => Div(Box::new(expr_l), Box::new(Val(r))),
+            Add(_, _) => Add(Box::new(expr_l), Box::new(Val(r))),
+            Sub(_, _) => Sub(Box::new(expr_l), Box::new(Val(r))),
+            _ => panic!("Not possible")
+        },
+        _ => panic!("can't reduce this one!")
+    }
+}
+
+fn find_x(optree: &Optree) -> u64 {
+    if let Op::Add(m1, m2) = &optree["root"] {
+        let lhs_expr = reduce(&make_expr_p2(&m1, optree));
+        let rhs_expr = reduce(&make_expr_p2(&m2, optree));
+
+        if is_val(&lhs_expr) {
+            let non_x_part = balance(rhs_expr, lhs_expr);
+            return evaluate(non_x_part).unwrap();
+        } else if is_val(&rhs_expr) {
+            let non_x_part = balance(lhs_expr, rhs_expr);
+            return evaluate(non_x_part).unwrap();
+        } else {
+            panic!("One of the sides has to reduce!");
+        }
+    } else {
+        panic!("We expect root to be a + op.");
+    }
+}
+
+fn balance(contains_x: Expr, receives_balance: Expr) -> Expr {
+    // Dig down the left hand expression tree until you find X.
+    // This function expects a reduced Expr in its first param,
+    // and a Val(_) expr in its second.
+    if !is_val(&receives_balance) {
+        panic!("The second param should be a Expr::Val(_)");
+    }
+
+    let mut result = receives_balance;
+    let mut contains_x = contains_x;
+
+    use Expr::*;
+    while !is_x(&contains_x) {
+        match contains_x {
+            Mul(l, r) if is_val(&l) => {
+                // e.g.: 3 * x = 5 --> x = 5 / 3
+                contains_x = *r;
+                result = Div(Box::new(result), l);
+            },
+            Mul(l, r) if is_val(&r) => {
+                // e.g.: x * 3 = 5 --> x = 5 / 3
+                contains_x = *l;
+                result = Div(Box::new(result), r);
+            },
+            Div(l, r) if is_val(&l) => {
+                // e.g.: 3 / x = 5 --> x = 3 / 5  (cross-multiply)
+                contains_x = *r;
+                result = Div(l, Box::new(result));
+            },
+            Div(l, r) if is_val(&r) => {
+                // e.g.: x / 3 = 5 --> x = 5 * 3
+                contains_x = *l;
+                result = Mul(Box::new(result), r);
+            },
+            Add(l, r) if is_val(&l) => {
+                // e.g.: 3 + x = 5 --> x = 5 - 3
+                contains_x = *r;
+                result = Sub(Box::new(result), l);
+            },
+            Add(l, r) if is_val(&r) => {
+                // e.g.: x + 3 = 5 --> x = 5 - 3
+                contains_x = *l;
+                result = Sub(Box::new(result), r);
+            },
+            Sub(l, r) if is_val(&l) => {
+                // e.g.: 3 - x = 5 --> x = 3 - 5
+                contains_x = *r;
+                result = Sub(l, Box::new(result));
+            },
+            Sub(l, r) if is_val(&r) => {
+                // e.g.: x - 3 = 5 --> x = 5 + 3
+                contains_x = *l;
+                result = Add(Box::new(result), r);
+            },
+            _ => panic!("unexpected expression on the x side!")
+        }
+    }
+
+    return result;
+}
+
+fn make_expr_p2(symbol: &str, optree: &Optree) -> Expr {
+    if symbol == "humn" {
+        return Expr::X;
+    }
+
+    match &optree[symbol] {
+        Op::Val(val) => Expr::Val(*val),
+        Op::Mul(m1, m2) => Expr::Mul(Box::new(make_expr_p2(&m1, optree)), Box::new(make_expr_p2(&m2, optree))),
+        Op::Div(m1, m2) => Expr::Div(Box::new(make_expr_p2(&m1, optree)), Box::new(make_expr_p2(&m2, optree))),
+        Op::Add(m1, m2) => Expr::Add(Box::new(make_expr_p2(&m1, optree)), Box::new(make_expr_p2(&m2, optree))),
+        Op::Sub(m1, m2) => Expr::Sub(Box::new(make_expr_p2(&m1, optree)), Box::new(make_expr_p2(&m2, optree)))
+    }
+}
+
+pub type Monkey = String;
+pub type Optree = HashMap<Monkey, Op>;
+
+pub enum Op {
+    Val(u64),
+    Mul(Monkey, Monkey),
+    Div(Monkey, Monkey),
+    Add(Monkey, Monkey),
+    Sub(Monkey, Monkey),
+}
+
+enum Expr {
+    Val(u64),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    X
+}
+
+#[derive(Debug)]
+pub struct OpParseError;
+
+impl LineError for OpParseError {
+    fn message(&self) -> String {
+        "Expected a number or an operation like \"nwvs + nsjr\"".to_string()
+    }
+}
+
+impl FromStr for Op {
+    type Err = OpParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        use Op::*;
+        if let Ok((m1, op, m2)) = scan_fmt!(value, "{} {} {}", Monkey, String, Monkey) {
+            return match op.as_str() {
+                "*" => Ok(Mul(m1, m2)),
+                "/" => Ok(Div(m1, m2)),
+                "+" => Ok(Add(m1, m2)),
+                "-" => Ok(Sub(m1, m2)),
+                _ => Err(OpParseError)
+            };
+        } else {
+            let res = value.parse().map_or(Err(OpParseError), |val| Ok(Val(val)));
+            return res;
+        }
+    }
+}
+
+impl Debug for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Op::*;
+        match self {
+            Val(x) => write!(f, "{}", x),
+            Mul(m1, m2) => write!(f, "{} * {}", &m1, &m2),
+            Div(m1, m2) => write!(f, "{} / {}", &m1, &m2),
+            Add(m1, m2) => write!(f, "{} + {}", &m1, &m2),
+            Sub(m1, m2) => write!(f, "{} - {}", &m1, &m2),
+        }
+    }
+}
+
+impl Op {
+    // The monkeys this one waits on.
+    fn operands(&self) -> Vec<&Monkey> {
+        match self {
+            Op::Val(_) => vec![],
+            Op::Mul(m1, m2) | Op::Div(m1, m2) | Op::Add(m1, m2) | Op::Sub(m1, m2) => vec![m1, m2]
+        }
+    }
+}
+
+// Every job that parses (`parse` reports the rest).
+fn scan_jobs(input: &Input) -> Vec<(Monkey, Op)> {
+    input.lines().filter_map(|line| parse_line(line).ok()).collect()
+}
+
+impl Validate for Day21 {
+    const ASSUMPTIONS: &'static [Assumption] = &[
+        Assumption {
+            description: "root adds two numbers, so part 2 can make them equal",
+            check: |input| match scan_jobs(input).into_iter().find(|(monkey, _)| monkey == "root") {
+                Some((_, Op::Add(..))) => Ok(()),
+                Some((_, op)) => Err(format!["root's job is \"{op:?}\""]),
+                None => Err("There's no root".to_string())
+            }
+        },
+        Assumption {
+            description: "humn yells a number",
+            check: |input| match scan_jobs(input).into_iter().find(|(monkey, _)| monkey == "humn") {
+                Some((_, Op::Val(_))) => Ok(()),
+                Some((_, op)) => Err(format!["humn's job is \"{op:?}\""]),
+                None => Err("There's no humn".to_string())
+            }
+        },
+        Assumption {
+            description: "Every monkey a monkey waits on has a job",
+            check: |input| {
+                let jobs = scan_jobs(input);
+                let monkeys: HashSet<&Monkey> = jobs.iter().map(|(monkey, _)| monkey).collect();
+                for (monkey, op) in &jobs {
+                    if let Some(missing) = op.operands().into_iter().find(|m| !monkeys.contains(m)) {
+                        return Err(format!["{monkey} waits on {missing}, who has no job"]);
+                    }
+                }
+                Ok(())
+            }
+        },
+        Assumption {
+            description: "No monkey is waited on by two others, so humn's number only matters once",
+            check: |input| {
+                let jobs = scan_jobs(input);
+                let mut waiting: HashMap<&Monkey, &Monkey> = HashMap::new();
+                for (monkey, op) in &jobs {
+                    for operand in op.operands() {
+                        if let Some(other) = waiting.insert(operand, monkey) {
+                            return Err(format!["{other} and {monkey} both wait on {operand}"]);
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    ];
+}
+
+impl Generate for Day21 {
+    const PARAMS: &'static [Param] = &[
+        Param { name: "depth", default: 20, help: "how many monkeys there are between root and humn" },
+        Param { name: "leaves", default: 35, help: "the percent chance that a monkey off humn's path yells a number instead of waiting" },
+        Param { name: "value", default: 100000, help: "roughly what each side of root yells (at most 2^40)" }
+    ];
+
+    // Both sides of root yell the same number, and humn yells the number that makes them match, so
+    // part 2's answer is humn's own number. Working down from what each monkey should yell keeps
+    // every subtraction positive and every division exact.
+    fn generate(rng: &mut Rng, params: &Params) -> String {
+        let mut troop = Troop { names: HashSet::from(["root".to_string(), "humn".to_string()]), jobs: vec![] };
+        let value = rng.range(1..=params.get("value").clamp(1, MAX_VALUE) * 2);
+        let (depth, leaves) = (params.get("depth"), params.get("leaves"));
+        let mut sides = [troop.monkey(rng, value, depth, leaves, true), troop.monkey(rng, value, depth, leaves, false)];
+        rng.shuffle(&mut sides);
+        troop.jobs.push(format!["root: {} + {}", sides[0], sides[1]]);
+        rng.shuffle(&mut troop.jobs);
+        troop.jobs.iter().map(|job| format!["{job}\n"]).collect()
+    }
+}
+
+// The biggest number any monkey will yell, so that nothing overflows.
+const MAX_YELL: u64 = 1 << 48;
+
+// The biggest `value` param, well under `MAX_YELL` so there's room to work down from it.
+const MAX_VALUE: u64 = 1 << 40;
+
+// The monkeys made up so far.
+struct Troop {
+    names: HashSet<Monkey>,
+    jobs: Vec<String>
+}
+
+impl Troop {
+    // A new monkey that yells `value`, with a tree of monkeys up to `depth` deep below it. If it's on
+    // humn's path, humn is exactly `depth` below it.
+    fn monkey(&mut self, rng: &mut Rng, value: u64, depth: u64, leaves: u64, humn_path: bool) -> Monkey {
+        let name = if humn_path && depth == 0 { "humn".to_string() } else { self.name(rng) };
+        if depth == 0 || !humn_path && rng.chance(leaves) {
+            self.jobs.push(format!["{name}: {value}"]);
+            return name;
+        }
+
+        let factors: Vec<u64> = (2..=20).filter(|f| value.is_multiple_of(*f)).collect();
+        let mut ops = vec!['-'];
+        if value >= 2 { ops.push('+'); }
+        if !factors.is_empty() { ops.push('*'); }
+        if value <= MAX_YELL / 20 { ops.push('/'); }
+        let op = *rng.choose(&ops);
+        let (lhs, rhs) = match op {
+            '+' => {
+                let a = rng.range(1..=value - 1);
+                (a, value - a)
+            },
+            '-' => {
+                let b = rng.range(1..=100);
+                (value + b, b)
+            },
+            '*' => {
+                let f = *rng.choose(&factors);
+                (value / f, f)
+            },
+            _ => {
+                let b = rng.range(2..=20);
+                (value * b, b)
+            }
+        };
+
+        let humn_left = humn_path && rng.chance(50);
+        let lhs = self.monkey(rng, lhs, depth - 1, leaves, humn_left);
+        let rhs = self.monkey(rng, rhs, depth - 1, leaves, humn_path && !humn_left);
+        self.jobs.push(format!["{name}: {lhs} {op} {rhs}"]);
+        name
+    }
+
+    fn name(&mut self, rng: &mut Rng) -> Monkey {
+        loop {
+            let name: String = (0..4).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+
+    use super::*;
+
+    #[test]
+    fn parse_number() {
+        let (monkey, op) = parse_line("ppvs: 3").unwrap();
+        assert_eq!(&monkey, "ppvs");
+        assert_eq!(format!["{:?}", op], "3");
+    }
+
+    #[test]
+    fn parse_operation() {
+        let (monkey, op) = parse_line("ppvs: nwvs + nsjr").unwrap();
+        assert_eq!(&monkey, "ppvs");
+        assert_eq!(format!["{:?}", op], "nwvs + nsjr");
+    }
+
+    #[test]
+    fn assumptions() {
+        use utils::validate::validate;
+        assert_eq![validate::<Day21>(&Input::load("sample.txt").unwrap()), Ok(vec![])];
+
+        let problems = |text| validate::<Day21>(&Input::from(text)).unwrap().into_iter().map(|v| v.problem).collect::<Vec<_>>();
+        assert_eq![problems("root: aaaa * humn\naaaa: 3\nhumn: 5"), ["root's job is \"aaaa * humn\""]];
+        assert_eq![problems("root: aaaa + humn\naaaa: 3\nhumn: aaaa - bbbb"), [
+            "humn's job is \"aaaa - bbbb\"",
+            "humn waits on bbbb, who has no job",
+            "root and humn both wait on aaaa"
+        ]];
+    }
+
+    #[test]
+    fn parse_sample() {
+        let input = std::fs::read_to_string("sample.txt").unwrap();
+        let optree = parse_lines(input.split("\n")).unwrap();
+
+        assert_eq!(format!("{:?}", optree["root"]), "pppw + sjmn");
+        assert_eq!(format!("{:?}", optree["sjmn"]), "drzm * dbpl");
+        assert_eq!(format!("{:?}", optree["ljgn"]), "2");
+    }
+
+    #[test]
+    fn part1_sample() {
+        let input = std::fs::read_to_string("sample.txt").unwrap();
+        let optree = parse_lines(input.split("\n")).unwrap();
+
+        assert_eq![evaluate(make_expr("root", &optree)).unwrap(), 152];
+    }
+
+    #[test]
+    fn part1_input() {
+        let input = std::fs::read_to_string("input.txt").unwrap();
+        let optree = parse_lines(input.split("\n")).unwrap();
+
+        assert_eq![evaluate(make_expr("root", &optree)).unwrap(), 56490240862410];
+    }
+
+    #[test]
+    fn generated_troops() {
+        for seed in 0..10 {
+            let input = utils::generate::generate::<Day21>(seed, &["depth=8".to_string()]).unwrap();
+            let optree = Day21::parse(&Input::from(input.as_str())).unwrap();
+            let Op::Val(humn) = optree["humn"] else { panic!("humn should yell a number") };
+            assert_eq!(Day21::part2(&optree), humn, "seed {seed}");
+            assert_eq!(Day21::part1(&optree) % 2, 0, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn generated_huge_value() {
+        let input = utils::generate::generate::<Day21>(7, &["depth=5".to_string(), format!["value={}", u64::MAX]]).unwrap();
+        let optree = Day21::parse(&Input::from(input.as_str())).unwrap();
+        let Op::Val(humn) = optree["humn"] else { panic!("humn should yell a number") };
+        assert_eq!(Day21::part2(&optree), humn);
+        assert!(Day21::part1(&optree) <= 2 * 2 * MAX_VALUE);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let input = std::fs::read_to_string("sample.txt").unwrap();
+        let optree = parse_lines(input.split("\n")).unwrap();
+
+        assert_eq![find_x(&optree), 301];
+    }
+
+    #[test]
+    fn part2_input() {
+        let input = std::fs::read_to_string("input.txt").unwrap();
+        let optree = parse_lines(input.split("\n")).unwrap();
+
+        assert_eq![find_x(&optree), 3403989691757];
+    }
+}
\ No newline at end of file
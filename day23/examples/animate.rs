@@ -0,0 +1,10 @@
+use day23::{spread_out, Day23};
+use utils::render::animate_from_args;
+use utils::solution::Solution;
+
+fn main() {
+    animate_from_args(|input, part, recorder| {
+        spread_out(&Day23::parse(input)?, part, recorder);
+        Ok(())
+    });
+}
@@ -0,0 +1,281 @@
+use std::fmt::Debug;
+use std::collections::{VecDeque, HashMap, HashSet};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use utils::error::InputError;
+use utils::input::Input;
+use utils::render::{Cell, Frame, Recorder};
+use utils::solution::{Part, Solution};
+use utils::vector::Vector2;
+use tracing::{debug, trace};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Vec<Elf>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        let tiles = input.grid(
+            |chr| match chr {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(format!["Unexpected tile '{chr}'"])
+            }
+        )?;
+
+        Ok(tiles
+            .positions()
+            .filter(|&pos| tiles[pos])
+            .map(|(r, c)| Elf::new(c as i64, r as i64))
+            .collect())
+    }
+
+    // Empty ground tiles (110 for sample, 4254 for input)
+    fn part1(elves: &Self::Input) -> u64 {
+        let mut elf_walk = ElfWalk::new(elves.clone());
+        trace!(elves = ?elf_walk.elves, "Start");
+
+        for round in 1..=10 {
+            if let WalkResult::Done = elf_walk.run_round() {
+                break;
+            }
+            trace!(round, elves = ?elf_walk.elves);
+        }
+
+        return elf_walk.empties();
+    }
+
+    // Round search completes (20 for sample, 992 for input)
+    fn part2(elves: &Self::Input) -> u64 {
+        let mut elf_walk = ElfWalk::new(elves.clone());
+
+        let mut round = 1;
+        while let WalkResult::Moves = elf_walk.run_round() {
+            round += 1;
+            if round % 100 == 0 {
+                debug!(round, "Still moving");
+            }
+            trace!(round, elves = ?elf_walk.elves);
+        }
+
+        return round;
+    }
+}
+
+/// Spread the elves out for part 1's ten rounds, or until they stop (part 2), recording the grove
+/// after each round.
+pub fn spread_out(elves: &[Elf], part: Part, recorder: &mut Recorder) {
+    let mut elf_walk = ElfWalk::new(elves.to_vec());
+    recorder.record(&Grove::new(&elf_walk.elves));
+    let rounds = if part == Part::One { 10 } else { usize::MAX };
+    for _ in 0..rounds {
+        if let WalkResult::Done = elf_walk.run_round() {
+            break;
+        }
+        recorder.record(&Grove::new(&elf_walk.elves));
+    }
+}
+
+// The smallest rectangle containing every elf.
+struct Grove {
+    elves: HashSet<Vector2<i64>>,
+    min: Vector2<i64>,
+    max: Vector2<i64>
+}
+
+impl Grove {
+    fn new(elves: &[Elf]) -> Self {
+        let min = Vector2::new([0, 1].map(|i| elves.iter().map(|elf| elf.pos[i]).min().unwrap()));
+        let max = Vector2::new([0, 1].map(|i| elves.iter().map(|elf| elf.pos[i]).max().unwrap()));
+        Grove { elves: elves.iter().map(|elf| elf.pos).collect(), min, max }
+    }
+}
+
+const ELF: Cell = Cell::new('#', [60, 200, 90]);
+const GROUND: Cell = Cell::new('.', [60, 40, 25]);
+
+impl Frame for Grove {
+    fn rows(&self) -> usize {
+        (self.max.y() - self.min.y() + 1) as usize
+    }
+
+    fn cols(&self) -> usize {
+        (self.max.x() - self.min.x() + 1) as usize
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Cell {
+        if self.elves.contains(&(self.min + Vector2::new([col as i64, row as i64]))) { ELF } else { GROUND }
+    }
+}
+
+struct ElfWalk {
+    elves: Vec<Elf>,
+    dir_order: VecDeque<Dir>
+}
+
+enum WalkResult {
+    Moves,
+    Done
+}
+
+impl ElfWalk {
+    fn new(elves: Vec<Elf>) -> Self {
+        Self {
+            elves,
+            dir_order: VecDeque::from([Dir::N, Dir::S, Dir::W, Dir::E])
+        }
+    }
+
+    fn run_round(&mut self) -> WalkResult {
+        let mut new_elves = self.elves.clone();
+        for elf in &mut new_elves {
+            let vacancies: HashMap<Dir, bool> = Dir::iter()
+                .map(
+                    |d| (d, self.vacant(elf.pos + d.unit()))
+                ).collect();
+
+            if vacancies.iter().all(|(_, vacant)| *vacant) {
+                continue; // If the elf has no adjacent elves, it doesn't move.
+            }
+
+            for dir in &self.dir_order {
+                let allowed = dir.dirs().iter()
+                    .all(
+                        |d| vacancies[d]
+                    );
+                if allowed {
+                    elf.propose(*dir);
+                    break;
+                }
+            }
+        }
+
+        // Count proposals
+        let mut proposals: HashMap<Vector2<i64>, u64> = HashMap::new();
+        for elf in &new_elves {
+            if let Some(next) = elf.next() {
+                proposals.entry(next).and_modify(|v| *v += 1).or_insert(1);
+            }
+        }
+
+        if proposals.is_empty() {
+            return WalkResult::Done;
+        }
+
+        for elf in &mut new_elves {
+            if let Some(next) = elf.next() {
+                if proposals[&next] == 1 {
+                    elf.accept();
+                } else {
+                    elf.reject();
+                }
+            }
+        }
+
+        self.elves = new_elves;
+        let first = self.dir_order.pop_front().unwrap();
+        self.dir_order.push_back(first);
+        return WalkResult::Moves;
+    }
+
+    fn vacant(&self, pt: Vector2<i64>) -> bool {
+        !self.elves.iter().any(|elf| elf.pos == pt)
+    }
+
+    fn empties(&self) -> u64 {
+        // Find the rectangle. Its bounds are smallest to largest x and y.
+        let x_min = self.elves.iter().map(|elf| elf.pos.x()).min().unwrap();
+        let x_max = self.elves.iter().map(|elf| elf.pos.x()).max().unwrap();
+        let y_min = self.elves.iter().map(|elf| elf.pos.y()).min().unwrap();
+        let y_max = self.elves.iter().map(|elf| elf.pos.y()).max().unwrap();
+        let width = (x_max - x_min) + 1;
+        let height = (y_max - y_min) + 1;
+        let total_squares = width * height;
+        let empties = total_squares as u64 - self.elves.len() as u64;
+        return empties
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Elf {
+    pos: Vector2<i64>,
+    proposal: Option<Dir>
+}
+
+impl Elf {
+    fn new(x: i64, y: i64) -> Self {
+        Self { pos: Vector2::new([x, y]), proposal: None }
+    }
+
+    fn propose(&mut self, d: Dir) {
+        self.proposal = Some(d);
+    }
+
+    fn next(&self) -> Option<Vector2<i64>> {
+        match self.proposal {
+            Some(dir) => Some(self.pos + dir.unit()),
+            None => None
+        }
+    }
+
+    fn accept(&mut self) {
+        self.pos = match self.next() {
+            Some(next) => next,
+            None => self.pos
+        };
+        self.proposal = None;
+    }
+
+    fn reject(&mut self) {
+        self.proposal = None;
+    }
+}
+
+impl Debug for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.pos.x(), self.pos.y())
+    }
+}
+
+#[derive(Clone, Copy, EnumIter, PartialEq, Eq, Hash)]
+enum Dir {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW
+}
+
+impl Dir {
+    fn unit(&self) -> Vector2<i64> {
+        use Dir::*;
+        Vector2::new(match self {
+            N => [0, -1],
+            NE => [1, -1],
+            E => [1, 0],
+            SE => [1, 1],
+            S => [0, 1],
+            SW => [-1, 1],
+            W => [-1, 0],
+            NW => [-1, -1]
+        })
+    }
+
+    fn dirs(&self) -> [Dir; 3] {
+        use Dir::*;
+        match self {
+            N => [NW, N, NE],
+            S => [SE, S, SW],
+            W => [SW, W, NW],
+            E => [NE, E, SE],
+            _ => panic!("Not a cardinal direction")
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use day24::{trek, Day24};
+use utils::render::animate_from_args;
+use utils::solution::Solution;
+
+fn main() {
+    animate_from_args(|input, part, recorder| {
+        trek(&Day24::parse(input)?, part, recorder);
+        Ok(())
+    });
+}
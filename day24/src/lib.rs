@@ -0,0 +1,579 @@
+use utils::error::InputError;
+use utils::grid2::Grid2;
+use utils::input::Input;
+use utils::search::{astar, Graph};
+use utils::render::{Cell, Frame, Recorder};
+use utils::generate::{Generate, Param, Params, Rng};
+use utils::solution::{Part, Solution};
+use utils::validate::{Assumption, Validate};
+use utils::vector::Vector2;
+use tracing::{info, trace};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Grid2<Square>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // Notes:
+    // 1. The problem doesn't have any south/north blowing blizzards in the last column.
+    //    No need to worry about storms blowing out the exit or appearing at the exit.
+    // 2. The problem inputs don't require you to wait to enter the board. You can always enter
+    //    immediately. Unsure if that's optimal, though.
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        // Initial idea: generate a 2d + 1d time volume that is a list of grids. Find the shortest
+        // path through that volume (only allowing for moving forward in time) that reaches the
+        // very last grid square at any time. The time length of the volume is the least common multiple
+        // of the board width & height, since all storms will repeat on that cadence.
+        // Or easier, just multiply the dimensions -- the puzzle input is 99x35, so 3*3*11 x 7*5,
+        // and so they're mutually prime and the LCM is their product.
+        let (blizzards, board_size) = parse_input(input)?;
+        Ok(board_volume(&blizzards, board_size))
+    }
+
+    // Fewest minutes required
+    fn part1(board_vol: &Self::Input) -> usize {
+        let shortest_path = path_to_end(board_vol);
+
+        trace_path(&shortest_path);
+        return shortest_path.len() - 1;
+    }
+
+    // Fewest minutes required for a return to start and back
+    fn part2(board_vol: &Self::Input) -> usize {
+        // I think part of this path violates the assumption that you can immediately move off the starting square.
+        // For the path backward, you might have to wait.
+        let shortest_path = path_to_end_then_start_then_end(board_vol);
+        return shortest_path.len() - 1;
+    }
+}
+
+impl Validate for Day24 {
+    const ASSUMPTIONS: &'static [Assumption] = &[
+        Assumption {
+            description: "The entry is the top row's first gap, and the exit the bottom row's last",
+            check: |input| {
+                let lines: Vec<&str> = input.lines().collect();
+                let (Some(top), Some(bottom)) = (lines.first(), lines.last()) else { return Ok(()) };
+                let width = top.len().max(2);
+                if *top != format!["#.{}", "#".repeat(width - 2)] {
+                    Err(format!["The top row is \"{top}\""])
+                } else if *bottom != format!["{}.#", "#".repeat(width - 2)] {
+                    Err(format!["The bottom row is \"{bottom}\""])
+                } else {
+                    Ok(())
+                }
+            }
+        },
+        Assumption {
+            description: "No blizzards blow up or down the entry and exit columns, through the gaps in the wall",
+            check: |input| {
+                let lines: Vec<&str> = input.lines().collect();
+                let width = lines.first().map_or(0, |line| line.len());
+                for (y, line) in lines.iter().enumerate() {
+                    for (x, c) in line.chars().enumerate() {
+                        if (x == 1 || x + 2 == width) && (c == '^' || c == 'v') {
+                            return Err(format!["Line {} has a {c} in column {}", y + 1, x + 1]);
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    ];
+}
+
+#[cfg(test)]
+fn parse(path: &str) -> (Vec<Blizz>, (usize, usize)) {
+    parse_input(&Input::load(path).unwrap()).unwrap()
+}
+
+fn parse_input(input: &Input) -> Result<(Vec<Blizz>, (usize, usize)), InputError> {
+    let mut blizzards = vec![];
+    let lines: Vec<&str> = input.lines().collect();
+
+    // Boards have a one-character wall of #'s around them.
+    if lines.len() < 3 || lines[0].len() < 3 {
+        return Err(InputError::new("Expected a board with a wall around it"));
+    }
+    let board_size = (lines[0].len() - 2, lines.len() - 2);
+
+    for (y, line) in lines.iter().skip(1).enumerate().take(board_size.1) {
+        if line.len() != board_size.0 + 2 {
+            return Err(InputError::new(format!["Expected {} squares in every row", board_size.0 + 2]).on_line(y + 2, line));
+        }
+        for (x, c) in line.chars().skip(1).enumerate().take(board_size.0) {
+            let dir_opt = Dir::from(c);
+            if let Some(dir) = dir_opt {
+                let blizz = Blizz::new(x, y, board_size, dir);
+                blizzards.push(blizz);
+            }
+        }
+    }
+    return Ok((blizzards, board_size));
+}
+
+fn board_volume(blizzards: &Vec<Blizz>, board_size: (usize, usize)) -> Vec<Grid2<Square>> {
+    let mut volume = vec![];
+    let volume_time_len = (board_size.0 * board_size.1) as u64;
+
+    for t in 0..volume_time_len {
+        let grid_at_t = board_at_t(blizzards, board_size, t);
+        volume.push(grid_at_t);
+    }
+
+    return volume;
+}
+
+fn board_at_t(blizzards: &Vec<Blizz>, board_size: (usize, usize), t: u64) -> Grid2<Square> {
+    let mut grid_at_t: Grid2<Square> = Grid2::new(board_size.1, board_size.0, Square::Open); // height rows and width columns
+    for b in blizzards {
+        let (c, r) = b.pos(t).map(|c| c as usize).into();
+        grid_at_t[r][c] = Square::Wall;
+    }
+    grid_at_t
+}
+
+type PosTime = (V, u64);
+
+fn path_to_end(volume: &Vec<Grid2<Square>>) -> Vec<PosTime> {
+    let (width, height) = (volume[0].cols() as i16, volume[0].rows() as i16);
+    let entry = V::new([0, -1]);
+    let start = V::new([0, 0]);
+    let start_time = 0;
+    let end = V::new([width - 1, height - 1]);
+    let exit = V::new([width - 1, height]);
+    bfs_volume(volume, entry, start, end, exit, start_time)
+}
+
+fn trace_path(path: &[PosTime]) {
+    for (p, t) in path {
+        trace!(t, ?p);
+    }
+}
+
+fn path_to_end_then_start_then_end(volume: &Vec<Grid2<Square>>) -> Vec<PosTime> {
+    let (width, height) = (volume[0].cols() as i16, volume[0].rows() as i16);
+    let entry = V::new([0, -1]);
+    let start = V::new([0, 0]);
+    let start_time = 0;
+    let end = V::new([width - 1, height - 1]);
+    let exit = V::new([width - 1, height]);
+
+    // Go the end.
+    let there = bfs_volume(volume, entry, start, end, exit, start_time);
+    trace_path(&there);
+
+    let time_there = there.len() as u64 - 1;
+    info!(time_there);
+
+    // Come back to the start.
+    let start_time_back = time_there + start_time;
+    let back = bfs_volume(volume, exit, end, start, entry, start_time_back);
+    trace_path(&back);
+
+    let time_back_again = back.len() as u64 - 1;
+    let start_time_there_again = time_back_again + start_time_back;
+    info!(time_back_again);
+
+    // Go back to the end again.
+    let there_again = bfs_volume(volume, entry, start, end, exit, start_time_there_again);
+    trace_path(&there_again);
+
+    let time_there_again = there_again.len() as u64 - 1;
+    let end_time = time_there_again + start_time_there_again;
+    info!(time_there_again);
+
+    let mut result = vec![];
+    result.extend(&there);
+    result.extend(back.iter().skip(1));
+    result.extend(there_again.iter().skip(1));
+
+    return result;
+}
+
+/// Walk the quickest path (part 1) or the quickest there, back and there again (part 2), recording
+/// the valley every minute.
+pub fn trek(volume: &Vec<Grid2<Square>>, part: Part, recorder: &mut Recorder) {
+    let path = match part {
+        Part::One => path_to_end(volume),
+        Part::Two => path_to_end_then_start_then_end(volume)
+    };
+    for &(pos, t) in &path {
+        recorder.record(&Minute { board: &volume[t as usize % volume.len()], pos });
+    }
+}
+
+// The valley at one minute, with its walls, and the expedition at `pos`.
+struct Minute<'a> {
+    board: &'a Grid2<Square>,
+    pos: V
+}
+
+const EXPEDITION: Cell = Cell::new('E', [250, 200, 40]);
+const BLIZZARD: Cell = Cell::new('*', [150, 200, 255]);
+const GROUND: Cell = Cell::new('.', [40, 40, 60]);
+const WALL: Cell = Cell::new('#', [120, 110, 100]);
+
+impl Frame for Minute<'_> {
+    fn rows(&self) -> usize {
+        self.board.rows() + 2
+    }
+
+    fn cols(&self) -> usize {
+        self.board.cols() + 2
+    }
+
+    // Rows and columns are one more than board positions, to leave room for the walls.
+    fn cell(&self, row: usize, col: usize) -> Cell {
+        let pos = V::new([col as i16 - 1, row as i16 - 1]);
+        let (entry, exit) = (V::new([0, -1]), V::new([self.board.cols() as i16 - 1, self.board.rows() as i16]));
+        if pos == self.pos {
+            EXPEDITION
+        } else if pos == entry || pos == exit {
+            GROUND
+        } else {
+            match self.board.get((pos.y() as isize, pos.x() as isize)) {
+                Some(Square::Open) => GROUND,
+                Some(Square::Wall) => BLIZZARD,
+                None => WALL
+            }
+        }
+    }
+}
+
+// The valley as a graph through spacetime: every minute you can move or wait, as long as the square
+// you end up on is open. You can always wait at the entry, which is outside the board.
+struct Valley<'a> {
+    volume: &'a Vec<Grid2<Square>>,
+    entry: V
+}
+
+impl Graph for Valley<'_> {
+    type Node = PosTime;
+
+    fn neighbors(&self, &(curr_pos, curr_t): &PosTime) -> Vec<PosTime> {
+        let next_t = curr_t + 1;
+        let t = (next_t % self.volume.len() as u64) as usize;
+        [Dir::S, Dir::E, Dir::W, Dir::N, Dir::Wait].into_iter()
+            .map(|next_dir| (curr_pos + next_dir.unit(), next_dir))
+            .filter(|&(next_pos, next_dir)|
+                self.volume[t].get((next_pos.y() as isize, next_pos.x() as isize)) == Some(&Square::Open) ||
+                (next_pos == self.entry && next_dir == Dir::Wait)
+            )
+            .map(|(next_pos, _)| (next_pos, next_t))
+            .collect()
+    }
+}
+
+// The quickest path from the entry to the exit, one step per minute, via the square next to the exit.
+fn bfs_volume(volume: &Vec<Grid2<Square>>, entry: V, _start: V, end: V, exit: V, start_time: u64) -> Vec<PosTime> {
+    // Every step takes a minute, so the manhattan distance to the end never overestimates.
+    let search = astar(
+        &Valley { volume, entry },
+        (entry, start_time),
+        |&(pos, _)| pos == end,
+        |&(pos, _)| pos.manhattan(&end) as u64
+    );
+
+    let &(_, end_t) = search.goal().expect("The end should always be reachable by waiting for the storms to pass");
+    let mut path = search.path(&(end, end_t)).unwrap();
+    path.push((exit, end_t + 1));
+    return path;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Wall
+}
+
+impl Default for Square {
+    fn default() -> Self {
+        Square::Open
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Blizz {
+    maj_axis: i16,   // Which row or column is the blizzard in?
+    axis_len: usize, // How long is the row or column the blizzard is in?
+    offset: i16,     // Where in that row or column does the blizzard start?
+    dir: Dir         // What direction does it blow?
+}
+
+impl Blizz {
+    fn new(x: usize, y: usize, board_size: (usize, usize), dir: Dir) -> Self {
+        use Dir::*;
+        let maj_axis = match dir {
+            N | S => x as i16,
+            E | W => y as i16,
+            _ => panic!("Invalid direction")
+        };
+        let offset = match dir {
+            N | S => y as i16,
+            E | W => x as i16,
+            _ => panic!("Invalid direction")
+        };
+        let axis_len = match dir {
+            N | S => board_size.1, // height
+            E | W => board_size.0, // width
+            _ => panic!("Invalid direction")
+        };
+
+        Self {
+            maj_axis,
+            axis_len,
+            offset,
+            dir
+        }
+    }
+
+    fn pos(&self, t: u64) -> V {
+        use Dir::*;
+        let t_offset = (t % self.axis_len as u64) as i16; // We only care about t mod the length of the axis
+        let axis_progress = self.offset + t_offset * self.dir.sign();
+        let axis_progress = axis_progress.rem_euclid(self.axis_len as i16);
+        let x = match self.dir {
+            N | S => self.maj_axis,
+            E | W => axis_progress as i16,
+            _ => panic!("invalid direction")
+        };
+        let y = match self.dir {
+            N | S => axis_progress as i16,
+            E | W => self.maj_axis,
+            _ => panic!("invalid direction")
+        };
+        V::new([x, y])
+    }
+}
+
+// (x, y), with y increasing downward.
+type V = Vector2<i16>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Dir {
+    N,
+    W,
+    E,
+    S,
+    Wait
+}
+
+impl Dir {
+    fn from(c: char) -> Option<Self> {
+        use Dir::*;
+        match c {
+            '^' => Some(N),
+            '>' => Some(E),
+            'v' => Some(S),
+            '<' => Some(W),
+            _ => None
+        }
+    }
+
+    fn unit(&self) -> V {
+        use Dir::*;
+        match self {
+            N => V::new([0, -1]),
+            E => V::new([1, 0]),
+            S => V::new([0, 1]),
+            W => V::new([-1, 0]),
+            Wait => V::new([0, 0])
+        }
+    }
+
+    fn sign(&self) -> i16
+    {
+        use Dir::*;
+        match self {
+            N | W => -1,
+            S | E => 1,
+            Wait => 0
+        }
+    }
+}
+
+impl Generate for Day24 {
+    const PARAMS: &'static [Param] = &[
+        Param { name: "width", default: 120, help: "how many squares across the valley is, inside the walls" },
+        Param { name: "height", default: 25, help: "how many squares down it is" },
+        Param { name: "density", default: 70, help: "the percent of squares that start with a blizzard" }
+    ];
+
+    // Like the real inputs, nothing blows up or down the entry and exit columns. Very dense valleys
+    // might have no way through.
+    fn generate(rng: &mut Rng, params: &Params) -> String {
+        let (width, height) = (params.get("width").max(1) as usize, params.get("height").max(1) as usize);
+        let mut board = format!["#.{}\n", "#".repeat(width)];
+        for _ in 0..height {
+            board.push('#');
+            for x in 0..width {
+                let blizzards: &[char] = if x == 0 || x == width - 1 { &['<', '>'] } else { &['<', '>', '^', 'v'] };
+                board.push(if rng.chance(params.get("density")) { *rng.choose(blizzards) } else { '.' });
+            }
+            board.push_str("#\n");
+        }
+        board + &format!["{}.#\n", "#".repeat(width)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assumptions() {
+        use utils::validate::validate;
+        assert_eq!(validate::<Day24>(&Input::load("sample.txt").unwrap()), Ok(vec![]));
+        let generated = utils::generate::generate::<Day24>(24, &["width=10", "height=5"].map(String::from)).unwrap();
+        assert_eq!(validate::<Day24>(&Input::from(generated.as_str())), Ok(vec![]));
+
+        let problems = |text| validate::<Day24>(&Input::from(text)).unwrap().into_iter().map(|v| v.problem).collect::<Vec<_>>();
+        assert_eq!(problems("#.####\n#>.<^#\n#.v..#\n####.#"), ["Line 2 has a ^ in column 5"]);
+        assert_eq!(problems("##.###\n#>.<.#\n#....#\n#.####"), ["The top row is \"##.###\""]);
+    }
+
+    #[test]
+    fn blizzard_initial_positions() {
+        let t = 0;
+        let board_size = (5, 7);
+        let b0 = Blizz::new(0, 1, board_size, Dir::E);
+        let b1 = Blizz::new(3, 3, board_size, Dir::S);
+        let b2 = Blizz::new(1, 0, board_size, Dir::N);
+        let b3 = Blizz::new(3, 3, board_size, Dir::W);
+
+        assert_eq!(b0.pos(t), (0, 1));
+        assert_eq!(b1.pos(t), (3, 3));
+        assert_eq!(b2.pos(t), (1, 0));
+        assert_eq!(b3.pos(t), (3, 3));
+    }
+
+    #[test]
+    fn blizzard_next_positions() {
+        let t = 1;
+        let board_size = (5, 7);
+        let b0 = Blizz::new(0, 1, board_size, Dir::E);
+        let b1 = Blizz::new(3, 3, board_size, Dir::S);
+        let b2 = Blizz::new(1, 0, board_size, Dir::N);
+        let b3 = Blizz::new(3, 3, board_size, Dir::W);
+
+        assert_eq!(b0.pos(t), (1, 1));
+        assert_eq!(b1.pos(t), (3, 4));
+        assert_eq!(b2.pos(t), (1, 6));
+        assert_eq!(b3.pos(t), (2, 3));
+    }
+
+    #[test]
+    fn blizzard_t_is_4_positions() {
+        let t = 4;
+        let board_size = (5, 7);
+        let b0 = Blizz::new(0, 1, board_size, Dir::E);
+        let b1 = Blizz::new(3, 3, board_size, Dir::S);
+        let b2 = Blizz::new(1, 0, board_size, Dir::N);
+        let b3 = Blizz::new(3, 3, board_size, Dir::W);
+
+        assert_eq!(b0.pos(t), (4, 1));
+        assert_eq!(b1.pos(t), (3, 0));
+        assert_eq!(b2.pos(t), (1, 3));
+        assert_eq!(b3.pos(t), (4, 3));
+    }
+
+    #[test]
+    fn parse_simple() {
+        let (blizzards, board_size) = parse("sample_simple.txt");
+        let b0 = Blizz::new(0, 1, board_size, Dir::E);
+        let b1 = Blizz::new(3, 3, board_size, Dir::S);
+
+        assert_eq!(blizzards, &[b0, b1]);
+    }
+
+    #[test]
+    fn board_vol() {
+        let board_size = (2,2);
+        let b0 = Blizz::new(1, 0, board_size, Dir::E);
+        let b1 = Blizz::new(0, 1, board_size, Dir::N);
+        let vol = board_volume(&vec![b0, b1], board_size);
+
+        use Square::*;
+        assert_eq!(vol[0], Grid2::from_rows(vec![vec![Open, Wall], vec![Wall, Open]]));
+        assert_eq!(vol[1], Grid2::from_rows(vec![vec![Wall, Open], vec![Open, Open]]));
+        assert_eq!(vol[2], Grid2::from_rows(vec![vec![Open, Wall], vec![Wall, Open]]));
+        assert_eq!(vol[3], Grid2::from_rows(vec![vec![Wall, Open], vec![Open, Open]]));
+        assert_eq!(vol.len(), 4);
+    }
+
+    #[test]
+    fn board_at_lcm_is_equivalent() {
+        let (blizzards, board_size) = parse("sample.txt");
+        let board_at_0 = board_at_t(&blizzards, board_size, 0);
+        let board_at_1 = board_at_t(&blizzards, board_size, 1);
+        let board_at_lcm = board_at_t(&blizzards, board_size, 24);
+        let board_at_lcm_plus_one = board_at_t(&blizzards, board_size, 25);
+
+        assert_eq!(board_size.0 * board_size.1, 24);
+        assert_eq!(board_at_0, board_at_lcm);
+        assert_eq!(board_at_1, board_at_lcm_plus_one);
+    }
+
+    #[test]
+    fn generated_valleys() {
+        for seed in 0..5 {
+            let args = ["width=9", "height=5", "density=40"].map(String::from);
+            let input = utils::generate::generate::<Day24>(seed, &args).unwrap();
+            let (blizzards, board_size) = parse_input(&Input::from(input.as_str())).unwrap();
+            assert_eq!(board_size, (9, 5));
+            assert!(blizzards.iter().all(|b| !matches!(b.dir, Dir::N | Dir::S) || (1..8).contains(&b.maj_axis)));
+
+            // It takes at least one minute per square from the entry to the exit.
+            let minutes = Day24::part1(&board_volume(&blizzards, board_size));
+            assert!(minutes >= 9 + 5, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn part1_sample() {
+        let (blizzards, board_size) = parse("sample.txt");
+        let board_vol = board_volume(&blizzards, board_size);
+        let shortest_path = path_to_end(&board_vol);
+        assert_eq!(shortest_path.len() - 1, 18);
+    }
+
+    #[test]
+    fn part1_input() {
+        let (blizzards, board_size) = parse("input.txt");
+        let board_vol = board_volume(&blizzards, board_size);
+        let shortest_path = path_to_end(&board_vol);
+
+        assert_eq!(shortest_path.len() - 1, 260);
+    }
+
+    #[test]
+    fn part2_sample_simple() {
+        let (blizzards, board_size) = parse("sample_simple.txt");
+        let board_vol = board_volume(&blizzards, board_size);
+        let shortest_path = path_to_end_then_start_then_end(&board_vol);
+        assert_eq!(shortest_path.len() - 1, 30);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let (blizzards, board_size) = parse("sample.txt");
+        let board_vol = board_volume(&blizzards, board_size);
+        let shortest_path = path_to_end_then_start_then_end(&board_vol);
+
+        assert_eq!(shortest_path.len() - 1, 54);
+    }
+
+    #[test]
+    fn part2_input() {
+        let (blizzards, board_size) = parse("input.txt");
+        let board_vol = board_volume(&blizzards, board_size);
+        let shortest_path = path_to_end_then_start_then_end(&board_vol);
+
+        assert_eq!(shortest_path.len() - 1, 747);
+    }
+}
\ No newline at end of file
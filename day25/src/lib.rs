@@ -0,0 +1,160 @@
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<String>;
+    type Answer1 = String;
+    type Answer2 = &'static str;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        parse_input(input)
+    }
+
+    // Enter into console
+    fn part1(nums: &Self::Input) -> String {
+        let dec_nums: Vec<i64> = nums.iter().map(|s| from_snafu(s)).collect();
+        let sum = dec_nums.iter().sum();
+        return to_snafu(sum);
+    }
+
+    // Day 25 only has one puzzle.
+    fn part2(_nums: &Self::Input) -> &'static str {
+        "n/a"
+    }
+}
+
+#[cfg(test)]
+fn parse(path: &str) -> Vec<String> {
+    parse_input(&Input::load(path).unwrap()).unwrap()
+}
+
+fn parse_input(input: &Input) -> Result<Vec<String>, InputError> {
+    input.parse_lines(|s| match s.chars().find(|c| !"=-012".contains(*c)) {
+        None => Ok(String::from(s)),
+        Some(c) => Err(format!["Invalid SNAFU digit '{c}'"])
+    })
+}
+
+fn from_snafu(input: &str) -> i64 {
+    let mut sum = 0;
+    let mut digit = 1;
+    for c in input.chars().rev() {
+        let val = to_dig(c);
+        sum += digit * val;
+        digit *= 5;
+    }
+    return sum;
+}
+
+fn to_dig(c: char) -> i64 {
+    match c {
+        '=' => -2,
+        '-' => -1,
+        '0' => 0,
+        '1' => 1,
+        '2' => 2,
+        _ => panic!("Invalid digit")
+    }
+}
+
+fn to_snafdig(d: i64) -> char {
+    match d {
+        -2 => '=',
+        -1 => '-',
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        _ => panic!("Invalid digit")
+    }
+}
+
+fn carry(d: i64) -> (i64, i64) {
+    match d {
+        0 => (0, 0),
+        1 => (0, 1),
+        2 => (0, 2),
+        3 => (1, -2),
+        4 => (1, -1),
+        5 => (1, 0),
+        _ => panic!("Invalid digit")
+    }
+}
+
+fn to_snafu(input: i64) -> String {
+    // First, turn the decimal number into a list of base-5 digits (0-4).
+    // Then, convert 3s and 4s into the snafu format by carrying a 1.
+    // 223 --> 23= --> 3== --> 1===
+    // 224 --> 23- --> 3=- --> 1==-
+
+    // The first digit in the list will be the least-significant digit.
+    let mut digits: Vec<i64> = vec![];
+    let mut rem = input;
+    if rem == 0 {
+        digits.push(0);
+    }
+    while rem > 0 {
+        let b5_dig = rem % 5;
+        rem /= 5;
+        digits.push(b5_dig);
+    }
+
+    // Handle carrying
+    // e.g. 444 --> (4)(4)(4) --> 4(4+1)(-1) --> (4+1)(0)(-1) --> (1)(0)(0)(-1) --> 100-
+    let mut carry_val = 0;
+    for d in &mut digits {
+        (carry_val, *d) = carry(*d + carry_val);
+    }
+
+    if carry_val != 0 {
+        digits.push(carry_val);
+    }
+
+    let result: String = digits.into_iter().rev().map(to_snafdig).collect();
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_sample() {
+        let nums = parse("sample.txt");
+        let dec_nums: Vec<i64> = nums.into_iter().map(|s| from_snafu(&s)).collect();
+        assert_eq!(dec_nums,
+        [
+            1747,
+            906,
+            198,
+            11,
+            201,
+            31,
+            1257,
+            32,
+            353,
+            107,
+            7,
+            3,
+            37,
+        ]);
+
+        let sum: i64 = dec_nums.iter().sum();
+        assert_eq!(sum, 4890);
+
+        assert_eq!(to_snafu(sum), "2=-1=0");
+    }
+
+    #[test]
+    fn part1_input() {
+        let nums = parse("input.txt");
+        let dec_nums: Vec<i64> = nums.into_iter().map(|s| from_snafu(&s)).collect();
+        let sum: i64 = dec_nums.iter().sum();
+
+        assert_eq!(to_snafu(sum), "2=--00--0220-0-21==1");
+    }
+}
\ No newline at end of file
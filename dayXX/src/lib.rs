@@ -0,0 +1,44 @@
+use utils::error::InputError;
+use utils::input::Input;
+use utils::solution::Solution;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+        input.parse_lines(|line| Ok::<_, InputError>(line.to_string()))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.len()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str) -> <DayXX as Solution>::Input {
+        DayXX::parse(&Input::load(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn sample_part1() {
+        assert_eq!(DayXX::part1(&parse("sample.txt")), 0);
+    }
+
+    #[test]
+    fn sample_part2() {
+        assert_eq!(DayXX::part2(&parse("sample.txt")), 0);
+    }
+}
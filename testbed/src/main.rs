@@ -1,6 +1,5 @@
-use std::io::{self, BufRead};
-
 use utils;
+use utils::input::Input;
 use lalrpop_util::*;
 
 mod ast;
@@ -18,25 +17,26 @@ fn main() {
     let ints = load_comma_separated_ints();
     println!("Loaded: {:?}", ints);
 
-    // Same thing, but from utils
-    let ints = utils::load_comma_separated_ints();
+    // Same thing, but from utils, which reads the rest of stdin up front.
+    let input = Input::load("-").unwrap();
+    let lines: Vec<&str> = input.lines().collect();
+    let ints = utils::load_comma_separated_ints(&input).unwrap();
     println!("Loaded (using utils): {:?}", ints);
 
     // Load some lines of Day 8 puzzle data.
-    let puzzles = utils::load_day8_2021_puzzle().take(2);
-    for (i, puzzle) in puzzles.enumerate() {
+    let puzzles = utils::load_day8_2021_puzzle(&Input::from(lines[1..3].join("\n").as_str())).unwrap();
+    for (i, puzzle) in puzzles.iter().enumerate() {
         println!("Puzzle {}: {:?}", i+1, puzzle);
     }
 
     // Load a 2d-coordinate line
-    let line = utils::load_line2d().next().unwrap();
+    let line = utils::load_line2d(&Input::from(lines[3])).unwrap().remove(0);
     println!("Loaded a line starting at x:{}, y:{} and ending at x:{}, y:{}", line.s.x, line.s.y, line.e.x, line.e.y);
 
 
     // Load a 10x10 grid
     let mut g = grid![];
-    for line in io::stdin().lock().lines().take(10) {
-        let l = line.unwrap();
+    for l in &lines[4..14] {
         g.push_row(l.chars().map(|c| c.to_digit(10).unwrap()).collect());
     }
 
@@ -0,0 +1,14 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::load_parser::CommaSepInt32sParser;
+
+static PARSER: LazyLock<CommaSepInt32sParser> = LazyLock::new(CommaSepInt32sParser::new);
+
+// Whatever parses prints back to something that parses the same.
+fuzz_target!(|line: &str| {
+    if let Ok(ints) = PARSER.parse(line) {
+        let printed = ints.iter().map(i32::to_string).collect::<Vec<_>>().join(",");
+        assert_eq!(PARSER.parse(&printed), Ok(ints));
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::crane_parser::MoveParser;
+
+static PARSER: LazyLock<MoveParser> = LazyLock::new(MoveParser::new);
+
+// Whatever parses prints back to something that parses the same.
+fuzz_target!(|line: &str| {
+    if let Ok((count, from, to)) = PARSER.parse(line) {
+        assert_eq!(PARSER.parse(&format!["move {count} from {from} to {to}"]), Ok((count, from, to)));
+    }
+});
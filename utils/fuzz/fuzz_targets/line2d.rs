@@ -0,0 +1,10 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::coord_2d_parser::Line2dParser;
+
+static PARSER: LazyLock<Line2dParser> = LazyLock::new(Line2dParser::new);
+
+fuzz_target!(|line: &str| {
+    let _ = PARSER.parse(line);
+});
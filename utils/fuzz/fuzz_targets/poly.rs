@@ -0,0 +1,14 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::coord_2d_parser::PolyParser;
+
+static PARSER: LazyLock<PolyParser> = LazyLock::new(PolyParser::new);
+
+// Whatever parses prints back to something that parses the same.
+fuzz_target!(|line: &str| {
+    if let Ok(coords) = PARSER.parse(line) {
+        let printed = coords.iter().map(|c| format!["{},{}", c.x, c.y]).collect::<Vec<_>>().join(" -> ");
+        assert_eq!(PARSER.parse(&printed), Ok(coords));
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::range_parser::RangePairParser;
+
+static PARSER: LazyLock<RangePairParser> = LazyLock::new(RangePairParser::new);
+
+// Whatever parses prints back to something that parses the same.
+fuzz_target!(|line: &str| {
+    if let Ok((a, b)) = PARSER.parse(line) {
+        let printed = format!["{}-{},{}-{}", a.start, a.end, b.start, b.end];
+        assert_eq!(PARSER.parse(&printed), Ok((a, b)));
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::signals_parser::PuzzleParser;
+
+static PARSER: LazyLock<PuzzleParser> = LazyLock::new(PuzzleParser::new);
+
+fuzz_target!(|line: &str| {
+    let _ = PARSER.parse(line);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::strategy_parser::{RoundParser, RoundOutcomeParser};
+use utils::rps::Symbols;
+
+static ROUND: LazyLock<RoundParser> = LazyLock::new(RoundParser::new);
+static ROUND_OUTCOME: LazyLock<RoundOutcomeParser> = LazyLock::new(RoundOutcomeParser::new);
+static SYMBOLS: LazyLock<Symbols> = LazyLock::new(Symbols::puzzle);
+
+// Whatever parses prints back to something that parses the same.
+fuzz_target!(|line: &str| {
+    if let Ok((theirs, mine)) = ROUND.parse(&SYMBOLS, line) {
+        let printed = format!["{} {}", SYMBOLS.theirs[theirs], SYMBOLS.mine[mine]];
+        assert_eq!(ROUND.parse(&SYMBOLS, &printed), Ok((theirs, mine)));
+    }
+    let _ = ROUND_OUTCOME.parse(&SYMBOLS, line);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+use std::sync::LazyLock;
+use libfuzzer_sys::fuzz_target;
+use utils::load::terminal_parser::CommandParser;
+
+static PARSER: LazyLock<CommandParser> = LazyLock::new(CommandParser::new);
+
+fuzz_target!(|line: &str| {
+    let _ = PARSER.parse(line);
+});
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+use lalrpop_util::ParseError;
+
+/// Puzzle input that couldn't be parsed, with as much context as is known about where: the file, the
+/// (1-based) line and column, and the text of the offending line.
+///
+/// Displays like a compiler diagnostic:
+/// ```text
+/// sample.txt:3:8: Invalid token at 7
+///   3 | move 1 frm 2 to 1
+///     |        ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+  pub message: String,
+  pub file: Option<String>,
+  pub line: Option<usize>,
+  pub column: Option<usize>,
+  pub text: Option<String>
+}
+
+impl InputError {
+  pub fn new(message: impl Display) -> Self {
+    InputError { message: message.to_string(), file: None, line: None, column: None, text: None }
+  }
+
+  /// Record the file the input came from, unless it's already known.
+  pub fn in_file(mut self, file: impl Display) -> Self {
+    self.file.get_or_insert(file.to_string());
+    self
+  }
+
+  /// Record the 1-based line number and text of the offending line, unless they're already known.
+  pub fn on_line(mut self, line: usize, text: &str) -> Self {
+    self.line.get_or_insert(line);
+    self.text.get_or_insert(text.to_string());
+    self
+  }
+
+  /// For errors from a block of input that starts after `lines` other lines, make the line number
+  /// relative to the whole input.
+  pub fn after_lines(mut self, lines: usize) -> Self {
+    self.line = self.line.map(|line| line + lines);
+    self
+  }
+}
+
+impl Display for InputError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let location: Vec<String> = [self.file.clone(), self.line.map(|l| l.to_string()), self.column.map(|c| c.to_string())]
+      .into_iter()
+      .flatten()
+      .collect();
+    if !location.is_empty() {
+      write!(f, "{}: ", location.join(":"))?;
+    }
+    write!(f, "{}", self.message)?;
+
+    if let Some(text) = &self.text {
+      let gutter = self.line.map(|l| l.to_string()).unwrap_or_default();
+      write!(f, "\n  {gutter} | {text}")?;
+      if let Some(column) = self.column {
+        write!(f, "\n  {:width$} | {:>column$}", "", "^", width = gutter.len())?;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl std::error::Error for InputError {}
+
+/// Errors from parsing a single line or string of input. Anything implementing this converts into an
+/// [`InputError`] with `?`; implement it for a day's own `FromStr` error types to do the same.
+pub trait LineError {
+  fn message(&self) -> String;
+
+  /// The 1-based column where parsing failed, if the parser knows it.
+  fn column(&self) -> Option<usize> {
+    None
+  }
+}
+
+impl<E: LineError> From<E> for InputError {
+  fn from(err: E) -> Self {
+    InputError { column: err.column(), ..InputError::new(err.message()) }
+  }
+}
+
+/// LALRPOP locations are byte offsets into the string being parsed.
+impl<T: Display, E: Display> LineError for ParseError<usize, T, E> {
+  fn message(&self) -> String {
+    self.to_string()
+  }
+
+  fn column(&self) -> Option<usize> {
+    match self {
+      ParseError::InvalidToken { location } | ParseError::UnrecognizedEOF { location, .. } => Some(location + 1),
+      ParseError::UnrecognizedToken { token: (start, _, _), .. } | ParseError::ExtraToken { token: (start, _, _) } => Some(start + 1),
+      ParseError::User { .. } => None
+    }
+  }
+}
+
+impl LineError for scan_fmt::parse::ScanError {
+  fn message(&self) -> String {
+    self.to_string()
+  }
+}
+
+impl LineError for std::num::ParseIntError {
+  fn message(&self) -> String {
+    format!["Invalid number: {self}"]
+  }
+}
+
+/// For `FromStr` implementations that don't say what went wrong.
+impl LineError for () {
+  fn message(&self) -> String {
+    "Invalid input".to_string()
+  }
+}
+
+impl LineError for String {
+  fn message(&self) -> String {
+    self.clone()
+  }
+}
+
+impl LineError for &str {
+  fn message(&self) -> String {
+    self.to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::load::load_parser::CommaSepInt32sParser;
+
+  #[test]
+  fn display_full_context() {
+    let err = InputError { column: Some(3), ..InputError::new("Bad token") }.on_line(12, "1,x,3").in_file("input.txt");
+    assert_eq!(err.to_string(), "input.txt:12:3: Bad token\n  12 | 1,x,3\n     |   ^");
+  }
+
+  #[test]
+  fn display_partial_context() {
+    assert_eq!(InputError::new("Empty input").to_string(), "Empty input");
+    assert_eq!(InputError::new("Invalid input").on_line(2, "D 4").to_string(), "2: Invalid input\n  2 | D 4");
+  }
+
+  #[test]
+  fn context_is_kept_once_known() {
+    let err = InputError::new("Bad").on_line(2, "inner").on_line(9, "outer").in_file("a.txt").in_file("b.txt");
+    assert_eq!((err.line, err.text.as_deref(), err.file.as_deref()), (Some(2), Some("inner"), Some("a.txt")));
+    assert_eq!(err.after_lines(5).line, Some(7));
+  }
+
+  #[test]
+  fn lalrpop_errors_have_columns() {
+    let err: InputError = CommaSepInt32sParser::new().parse("1,2,x").unwrap_err().into();
+    assert_eq!(err.column, Some(5));
+  }
+}
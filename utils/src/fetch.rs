@@ -0,0 +1,293 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Something that can GET a page from adventofcode.com. `Ureq` does it for real (with the `http`
+/// feature); tests use a stand-in that serves canned pages.
+pub trait Http {
+  /// The body of `url`, requested with the `session` cookie. Anything but a 200 is an error.
+  fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+}
+
+impl<H: Http> Http for &H {
+  fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+    (*self).get(url, session)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+  /// A download was needed, but `AOC_SESSION` isn't set.
+  NoSession,
+  /// The server answered with something other than 200 OK, e.g. 404 for a puzzle that isn't out yet.
+  Status { url: String, status: u16 },
+  /// The request didn't get an answer at all.
+  Transport(String),
+  /// The cache couldn't be read or written.
+  Cache { path: PathBuf, message: String }
+}
+
+impl Display for FetchError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      FetchError::NoSession => write!(f, "Set AOC_SESSION to your adventofcode.com session cookie to download inputs"),
+      FetchError::Status { url, status } => write!(f, "{url} answered {status}"),
+      FetchError::Transport(message) => write!(f, "{message}"),
+      FetchError::Cache { path, message } => write!(f, "Couldn't cache \"{}\": {message}", path.display())
+    }
+  }
+}
+
+impl std::error::Error for FetchError {}
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// Downloads puzzle inputs and pages, keeping a copy of everything under a cache directory so that
+/// nothing is ever downloaded twice, and waiting between downloads so the site isn't hammered.
+///
+/// The cache has one directory per puzzle: `<cache>/2022/16/input.txt` and `<cache>/2022/16/puzzle.html`.
+pub struct Fetcher<H: Http> {
+  http: H,
+  session: Option<String>,
+  cache_dir: PathBuf,
+  base_url: String,
+  min_interval: Duration,
+  last_request: Option<Instant>
+}
+
+impl<H: Http> Fetcher<H> {
+  pub fn new(http: H, session: Option<String>, cache_dir: impl Into<PathBuf>) -> Self {
+    Fetcher {
+      http,
+      session,
+      cache_dir: cache_dir.into(),
+      base_url: BASE_URL.to_string(),
+      min_interval: Duration::from_secs(3),
+      last_request: None
+    }
+  }
+
+  /// A fetcher using the session cookie in `AOC_SESSION` and the cache in `AOC_CACHE`, which defaults
+  /// to `~/.cache/aoc`.
+  pub fn from_env(http: H) -> Self {
+    let cache_dir = std::env::var_os("AOC_CACHE").map(PathBuf::from).unwrap_or_else(||
+      PathBuf::from(std::env::var_os("HOME").unwrap_or_default()).join(".cache").join("aoc")
+    );
+    Fetcher::new(http, std::env::var("AOC_SESSION").ok().filter(|s| !s.is_empty()), cache_dir)
+  }
+
+  /// Download from somewhere other than adventofcode.com, like a local test server.
+  pub fn base_url(mut self, base_url: &str) -> Self {
+    self.base_url = base_url.trim_end_matches('/').to_string();
+    self
+  }
+
+  /// The least time to leave between downloads (3 seconds by default).
+  pub fn min_interval(mut self, min_interval: Duration) -> Self {
+    self.min_interval = min_interval;
+    self
+  }
+
+  /// The puzzle input for a day.
+  pub fn input(&mut self, year: u16, day: u8) -> Result<String, FetchError> {
+    self.cached(year, day, "input.txt", &format!["/{year}/day/{day}/input"])
+  }
+
+  /// The text of every `<pre><code>` block on a day's puzzle page, which are the examples (and
+  /// sometimes a few snippets of them). Only the first part's are there until it's solved, and the
+  /// cached page isn't refreshed, so delete `puzzle.html` from the cache to get the second part's.
+  pub fn examples(&mut self, year: u16, day: u8) -> Result<Vec<String>, FetchError> {
+    let page = self.cached(year, day, "puzzle.html", &format!["/{year}/day/{day}"])?;
+    Ok(code_blocks(&page))
+  }
+
+  // The cached file, downloading it from `path` on the site first if there isn't one.
+  fn cached(&mut self, year: u16, day: u8, file: &str, path: &str) -> Result<String, FetchError> {
+    let cache_path = self.cache_dir.join(year.to_string()).join(format!["{day:02}"]).join(file);
+    if cache_path.exists() {
+      return std::fs::read_to_string(&cache_path).map_err(|e| cache_error(&cache_path, e));
+    }
+
+    let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+    if let Some(wait) = self.last_request.and_then(|last| self.min_interval.checked_sub(last.elapsed())) {
+      std::thread::sleep(wait);
+    }
+    self.last_request = Some(Instant::now());
+    let body = self.http.get(&format!["{}{path}", self.base_url], session)?;
+
+    std::fs::create_dir_all(cache_path.parent().unwrap())
+      .and_then(|_| std::fs::write(&cache_path, &body))
+      .map_err(|e| cache_error(&cache_path, e))?;
+    Ok(body)
+  }
+}
+
+fn cache_error(path: &Path, err: std::io::Error) -> FetchError {
+  FetchError::Cache { path: path.to_path_buf(), message: err.to_string() }
+}
+
+// The contents of each `<pre><code>...</code></pre>` in `html`, with tags like `<em>` removed and
+// entities decoded.
+fn code_blocks(html: &str) -> Vec<String> {
+  html.split("<pre><code>")
+    .skip(1)
+    .filter_map(|block| block.split_once("</code></pre>"))
+    .map(|(code, _)| unescape(&strip_tags(code)))
+    .collect()
+}
+
+fn strip_tags(html: &str) -> String {
+  let mut text = String::with_capacity(html.len());
+  let mut in_tag = false;
+  for c in html.chars() {
+    match c {
+      '<' => in_tag = true,
+      '>' if in_tag => in_tag = false,
+      _ if !in_tag => text.push(c),
+      _ => ()
+    }
+  }
+  text
+}
+
+fn unescape(text: &str) -> String {
+  // &amp; goes last so that "&amp;lt;" becomes "&lt;" rather than "<".
+  text.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&#39;", "'").replace("&amp;", "&")
+}
+
+/// The real thing, using `ureq`.
+#[cfg(feature = "http")]
+pub struct Ureq;
+
+#[cfg(feature = "http")]
+impl Http for Ureq {
+  fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+    let response = ureq::get(url)
+      .header("Cookie", &format!["session={session}"])
+      // Advent of Code asks that automated requests say where they come from.
+      .header("User-Agent", "github.com/mmcknett/advent-of-code-2022 utils::fetch")
+      .call();
+    match response {
+      Ok(mut response) => response.body_mut().read_to_string().map_err(|e| FetchError::Transport(e.to_string())),
+      Err(ureq::Error::StatusCode(status)) => Err(FetchError::Status { url: url.to_string(), status }),
+      Err(err) => Err(FetchError::Transport(err.to_string()))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::cell::RefCell;
+  use std::collections::HashMap;
+
+  // Serves canned pages and remembers what was asked for.
+  #[derive(Default)]
+  struct StandIn {
+    pages: HashMap<String, String>,
+    requests: RefCell<Vec<String>>
+  }
+
+  impl Http for StandIn {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+      assert_eq!(session, "abc123");
+      self.requests.borrow_mut().push(url.to_string());
+      self.pages.get(url).cloned().ok_or(FetchError::Status { url: url.to_string(), status: 404 })
+    }
+  }
+
+  fn stand_in() -> StandIn {
+    let page = "<p>For example:</p>\n<pre><code>1 &lt; 2\n<em>3</em>\n</code></pre>\n<p>Then <code>x</code>.</p><pre><code>a&amp;b\n</code></pre>";
+    StandIn {
+      pages: HashMap::from([
+        ("https://adventofcode.com/2022/day/1/input".to_string(), "1000\n2000\n".to_string()),
+        ("https://adventofcode.com/2022/day/1".to_string(), page.to_string())
+      ]),
+      ..StandIn::default()
+    }
+  }
+
+  #[test]
+  fn downloads_once() {
+    let cache = tempfile::tempdir().unwrap();
+    let http = stand_in();
+    let mut fetcher = Fetcher::new(&http, Some("abc123".to_string()), cache.path()).min_interval(Duration::ZERO);
+
+    assert_eq!(fetcher.input(2022, 1), Ok("1000\n2000\n".to_string()));
+    assert_eq!(fetcher.input(2022, 1), Ok("1000\n2000\n".to_string()));
+    assert_eq!(http.requests.borrow().len(), 1);
+    assert!(cache.path().join("2022/01/input.txt").exists());
+
+    // A fresh fetcher with no session still reads the cache.
+    let mut offline = Fetcher::new(&http, None, cache.path());
+    assert_eq!(offline.input(2022, 1), Ok("1000\n2000\n".to_string()));
+    assert_eq!(offline.input(2022, 2), Err(FetchError::NoSession));
+  }
+
+  #[test]
+  fn finds_examples() {
+    let cache = tempfile::tempdir().unwrap();
+    let http = stand_in();
+    let mut fetcher = Fetcher::new(&http, Some("abc123".to_string()), cache.path()).min_interval(Duration::ZERO);
+    assert_eq!(fetcher.examples(2022, 1), Ok(vec!["1 < 2\n3\n".to_string(), "a&b\n".to_string()]));
+    assert_eq!(unescape("&amp;lt;"), "&lt;");
+  }
+
+  #[test]
+  fn errors_are_not_cached() {
+    let cache = tempfile::tempdir().unwrap();
+    let http = stand_in();
+    let mut fetcher = Fetcher::new(&http, Some("abc123".to_string()), cache.path()).min_interval(Duration::ZERO);
+    let missing = Err(FetchError::Status { url: "https://adventofcode.com/2022/day/26/input".to_string(), status: 404 });
+    assert_eq!(fetcher.input(2022, 26), missing);
+    assert_eq!(fetcher.input(2022, 26), missing);
+    assert_eq!(http.requests.borrow().len(), 2);
+  }
+
+  #[test]
+  fn waits_between_downloads() {
+    let cache = tempfile::tempdir().unwrap();
+    let http = stand_in();
+    let mut fetcher = Fetcher::new(&http, Some("abc123".to_string()), cache.path()).min_interval(Duration::from_millis(50));
+    let start = Instant::now();
+    fetcher.input(2022, 1).unwrap();
+    fetcher.examples(2022, 1).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    // Reading the cache doesn't wait.
+    let start = Instant::now();
+    fetcher.input(2022, 1).unwrap();
+    assert!(start.elapsed() < Duration::from_millis(50));
+  }
+
+  // The real client against a one-request HTTP server on a local port.
+  #[cfg(feature = "http")]
+  #[test]
+  fn ureq_sends_the_session() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!["http://{}", listener.local_addr().unwrap()];
+    let server = std::thread::spawn(move || {
+      let (stream, _) = listener.accept().unwrap();
+      let mut reader = BufReader::new(stream.try_clone().unwrap());
+      let mut request = vec![];
+      let mut line = String::new();
+      while reader.read_line(&mut line).unwrap() > 2 {
+        request.push(line.trim_end().to_string());
+        line.clear();
+      }
+      let mut stream = stream;
+      write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n42\n17\n").unwrap();
+      request
+    });
+
+    let cache = tempfile::tempdir().unwrap();
+    let mut fetcher = Fetcher::new(Ureq, Some("abc123".to_string()), cache.path()).base_url(&base_url);
+    assert_eq!(fetcher.input(2022, 3), Ok("42\n17\n".to_string()));
+    let request = server.join().unwrap();
+    assert_eq!(request[0], "GET /2022/day/3/input HTTP/1.1");
+    assert!(request.iter().any(|header| header.eq_ignore_ascii_case("cookie: session=abc123")));
+  }
+}
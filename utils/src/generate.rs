@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use crate::solution::Solution;
+
+/// A small, seeded random number generator (SplitMix64). It's its own so that a seed makes the same
+/// input on every machine and with every version of everything, which is the point of having a seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+  pub fn new(seed: u64) -> Self {
+    Rng(seed)
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = self.0;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+  }
+
+  /// A number in `0..n`. (Slightly biased toward small numbers when `n` is huge, which doesn't matter here.)
+  pub fn below(&mut self, n: u64) -> u64 {
+    assert!(n > 0, "Nothing is below 0");
+    self.next_u64() % n
+  }
+
+  /// A number in `range`, e.g. `rng.range(2..=4)` for a robot's ore cost.
+  pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+    let (start, end) = range.into_inner();
+    match end - start {
+      u64::MAX => self.next_u64(), // Every u64, so there's no `n` to take it below.
+      width => start + self.below(width + 1)
+    }
+  }
+
+  /// True `percent`% of the time.
+  pub fn chance(&mut self, percent: u64) -> bool {
+    self.below(100) < percent
+  }
+
+  pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+    &items[self.below(items.len() as u64) as usize]
+  }
+
+  pub fn shuffle<T>(&mut self, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+      items.swap(i, self.below(i as u64 + 1) as usize);
+    }
+  }
+}
+
+/// One knob a generator has, like the number of valves, set with `name=value` on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+  pub name: &'static str,
+  pub default: u64,
+  pub help: &'static str
+}
+
+/// The value of each of a generator's params: the one given, or its default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params(HashMap<&'static str, u64>);
+
+impl Params {
+  /// Read `name=value` args, rejecting any name that isn't one of `params`.
+  pub fn parse(params: &'static [Param], args: &[String]) -> Result<Self, String> {
+    let mut values: HashMap<_, _> = params.iter().map(|param| (param.name, param.default)).collect();
+    for arg in args {
+      let (name, value) = arg.split_once('=').ok_or(format!["Expected name=value, not \"{arg}\""])?;
+      let param = params.iter().find(|param| param.name == name).ok_or(format!["Unknown parameter \"{name}\""])?;
+      let value = value.parse().map_err(|_| format!["Invalid {name} \"{value}\""])?;
+      values.insert(param.name, value);
+    }
+    Ok(Params(values))
+  }
+
+  /// The value of `name`, which has to be one of the generator's params.
+  pub fn get(&self, name: &str) -> u64 {
+    *self.0.get(name).unwrap_or_else(|| panic!("No parameter named {name}"))
+  }
+}
+
+/// A day that can make up inputs: random, but valid for its solver, and as big as asked for. Use them
+/// to find out how a solver scales, or to compare it against a slower, simpler answer.
+pub trait Generate: Solution {
+  /// The generator's knobs, with their defaults (which make something about as big as a real input).
+  const PARAMS: &'static [Param];
+
+  /// Why `params` are out of range for the generator, if they are (e.g. too big to make in a
+  /// reasonable time). By default, anything goes.
+  fn check(_params: &Params) -> Result<(), String> {
+    Ok(())
+  }
+
+  fn generate(rng: &mut Rng, params: &Params) -> String;
+}
+
+/// A type-erased `generate::<G>`, so generators for every day fit in one table.
+pub type Generator = fn(u64, &[String]) -> Result<String, String>;
+
+/// An input for day `G` from `seed` and `name=value` args. Unknown or out of range params are an error
+/// that lists the ones the day has.
+pub fn generate<G: Generate>(seed: u64, args: &[String]) -> Result<String, String> {
+  let params = Params::parse(G::PARAMS, args)
+    .and_then(|params| G::check(&params).map(|_| params))
+    .map_err(|err| format!["{err}\n\nDay {} takes:\n{}", G::DAY, usage(G::PARAMS)])?;
+  Ok(G::generate(&mut Rng::new(seed), &params))
+}
+
+fn usage(params: &[Param]) -> String {
+  params.iter()
+    .map(|param| format!["  {:<16} {}", format!["{}={}", param.name, param.default], param.help])
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::error::InputError;
+  use crate::input::Input;
+
+  #[test]
+  fn same_seed_same_numbers() {
+    let numbers = |seed| {
+      let mut rng = Rng::new(seed);
+      (0..10).map(|_| rng.range(1..=6)).collect::<Vec<_>>()
+    };
+    assert_eq!(numbers(7), numbers(7));
+    assert_ne!(numbers(7), numbers(8));
+    assert!(numbers(7).iter().all(|n| (1..=6).contains(n)));
+
+    let mut rng = Rng::new(7);
+    assert_ne!(rng.range(0..=u64::MAX), rng.range(0..=u64::MAX));
+    assert_eq!(rng.range(u64::MAX..=u64::MAX), u64::MAX);
+  }
+
+  #[test]
+  fn shuffle_keeps_everything() {
+    let mut items: Vec<u32> = (0..20).collect();
+    Rng::new(3).shuffle(&mut items);
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+  }
+
+  struct Count;
+
+  impl Solution for Count {
+    const DAY: u8 = 0;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+      Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+      input.len()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+      input.len()
+    }
+  }
+
+  impl Generate for Count {
+    const PARAMS: &'static [Param] = &[
+      Param { name: "lines", default: 3, help: "how many lines (at most 1000)" },
+      Param { name: "max", default: 9, help: "the biggest number" }
+    ];
+
+    fn check(params: &Params) -> Result<(), String> {
+      match params.get("lines") {
+        lines if lines > 1000 => Err(format!["lines can be at most 1000, not {lines}"]),
+        _ => Ok(())
+      }
+    }
+
+    fn generate(rng: &mut Rng, params: &Params) -> String {
+      (0..params.get("lines")).map(|_| format!["{}\n", rng.range(0..=params.get("max"))]).collect()
+    }
+  }
+
+  fn args(args: &str) -> Vec<String> {
+    args.split_whitespace().map(String::from).collect()
+  }
+
+  #[test]
+  fn params_have_defaults() {
+    assert_eq!(generate::<Count>(1, &[]).unwrap().lines().count(), 3);
+    assert_eq!(generate::<Count>(1, &args("lines=5 max=0")), Ok("0\n".repeat(5)));
+    assert_eq!(generate::<Count>(1, &args("lines=5")), generate::<Count>(1, &args("lines=5")));
+  }
+
+  #[test]
+  fn bad_params() {
+    let err = generate::<Count>(1, &args("rows=5")).unwrap_err();
+    assert_eq!(err, "Unknown parameter \"rows\"\n\nDay 0 takes:\n  lines=3          how many lines (at most 1000)\n  max=9            the biggest number");
+    assert!(generate::<Count>(1, &args("lines=1001")).unwrap_err().starts_with("lines can be at most 1000, not 1001\n"));
+    assert!(generate::<Count>(1, &args("lines=many")).is_err());
+    assert!(generate::<Count>(1, &args("lines")).is_err());
+  }
+}
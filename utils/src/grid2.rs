@@ -0,0 +1,215 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use crate::error::InputError;
+use crate::input::Input;
+
+/// A (row, column) position in a grid.
+pub type Pos = (usize, usize);
+
+/// A (row, column) step between positions, e.g. `(-1, 0)` is up.
+pub type Delta = (isize, isize);
+
+/// Right, up, left, down.
+pub const DELTAS4: [Delta; 4] = [(0, 1), (-1, 0), (0, -1), (1, 0)];
+
+/// The four orthogonal steps, then the four diagonals.
+pub const DELTAS8: [Delta; 8] = [(0, 1), (-1, 0), (0, -1), (1, 0), (-1, 1), (-1, -1), (1, -1), (1, 1)];
+
+/// A dense, rectangular grid stored row by row.
+///
+/// Index with a [`Pos`] (`grid[(r, c)]`) or a row then a column (`grid[r][c]`); both panic out of
+/// bounds. [`get`](Grid2::get) takes signed coordinates and returns `None` off the edge, so a
+/// neighbor can be looked up without checking for underflow first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid2<T> {
+  rows: usize,
+  cols: usize,
+  cells: Vec<T>
+}
+
+impl<T: Clone> Grid2<T> {
+  pub fn new(rows: usize, cols: usize, fill: T) -> Self {
+    Grid2 { rows, cols, cells: vec![fill; rows * cols] }
+  }
+}
+
+impl<T> Grid2<T> {
+  /// Panics if the rows aren't all the same length.
+  pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+    let cols = rows.first().map_or(0, Vec::len);
+    assert!(rows.iter().all(|row| row.len() == cols), "Grid rows must all be the same length");
+    Grid2 { rows: rows.len(), cols, cells: rows.into_iter().flatten().collect() }
+  }
+
+  /// Parse a map with one character per cell, e.g. `Grid2::from_str(input, |c| Ok::<_, InputError>(c == '#'))`.
+  pub fn from_str<E: Into<InputError>>(map: &str, cell: impl FnMut(char) -> Result<T, E>) -> Result<Self, InputError> {
+    Input::from(map).grid(cell)
+  }
+
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  pub fn cols(&self) -> usize {
+    self.cols
+  }
+
+  /// (rows, columns)
+  pub fn size(&self) -> (usize, usize) {
+    (self.rows, self.cols)
+  }
+
+  pub fn contains(&self, (r, c): (isize, isize)) -> bool {
+    r >= 0 && c >= 0 && (r as usize) < self.rows && (c as usize) < self.cols
+  }
+
+  pub fn get(&self, (r, c): (isize, isize)) -> Option<&T> {
+    self.contains((r, c)).then(|| &self.cells[r as usize * self.cols + c as usize])
+  }
+
+  pub fn get_mut(&mut self, (r, c): (isize, isize)) -> Option<&mut T> {
+    self.contains((r, c)).then(|| &mut self.cells[r as usize * self.cols + c as usize])
+  }
+
+  /// The position one `delta` away from `pos`, if it's on the grid.
+  pub fn step(&self, (r, c): Pos, (dr, dc): Delta) -> Option<Pos> {
+    let next = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+    (next.0 < self.rows && next.1 < self.cols).then_some(next)
+  }
+
+  /// The orthogonal neighbors of `pos` that are on the grid.
+  pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    DELTAS4.into_iter().filter_map(move |delta| self.step(pos, delta))
+  }
+
+  /// The orthogonal and diagonal neighbors of `pos` that are on the grid.
+  pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    DELTAS8.into_iter().filter_map(move |delta| self.step(pos, delta))
+  }
+
+  /// Every position from `pos` (not included) to the edge of the grid, stepping by `delta`.
+  pub fn ray(&self, pos: Pos, delta: Delta) -> impl Iterator<Item = Pos> + '_ {
+    std::iter::successors(self.step(pos, delta), move |&next| self.step(next, delta))
+  }
+
+  pub fn row(&self, r: usize) -> impl Iterator<Item = &T> {
+    self[r].iter()
+  }
+
+  pub fn col(&self, c: usize) -> impl Iterator<Item = &T> {
+    assert!(c < self.cols, "Column {c} is out of bounds");
+    self.cells.iter().skip(c).step_by(self.cols)
+  }
+
+  /// Every cell, row by row.
+  pub fn iter(&self) -> impl Iterator<Item = &T> {
+    self.cells.iter()
+  }
+
+  /// Every position, row by row.
+  pub fn positions(&self) -> impl Iterator<Item = Pos> {
+    let cols = self.cols;
+    (0..self.rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+  }
+
+  pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid2<U> {
+    Grid2 { rows: self.rows, cols: self.cols, cells: self.cells.iter().map(f).collect() }
+  }
+}
+
+impl<T> Index<Pos> for Grid2<T> {
+  type Output = T;
+
+  fn index(&self, (r, c): Pos) -> &T {
+    assert!(r < self.rows && c < self.cols, "({r}, {c}) is outside a {}x{} grid", self.rows, self.cols);
+    &self.cells[r * self.cols + c]
+  }
+}
+
+impl<T> IndexMut<Pos> for Grid2<T> {
+  fn index_mut(&mut self, (r, c): Pos) -> &mut T {
+    assert!(r < self.rows && c < self.cols, "({r}, {c}) is outside a {}x{} grid", self.rows, self.cols);
+    &mut self.cells[r * self.cols + c]
+  }
+}
+
+/// A whole row, so `grid[r][c]` works too.
+impl<T> Index<usize> for Grid2<T> {
+  type Output = [T];
+
+  fn index(&self, r: usize) -> &[T] {
+    &self.cells[r * self.cols..(r + 1) * self.cols]
+  }
+}
+
+impl<T> IndexMut<usize> for Grid2<T> {
+  fn index_mut(&mut self, r: usize) -> &mut [T] {
+    &mut self.cells[r * self.cols..(r + 1) * self.cols]
+  }
+}
+
+/// One line per row, with no separator between cells, e.g. a map of `char`s prints as it was parsed.
+impl<T: Display> Display for Grid2<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for r in 0..self.rows {
+      if r > 0 {
+        writeln!(f)?;
+      }
+      for cell in self.row(r) {
+        write!(f, "{cell}")?;
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn digits(map: &str) -> Grid2<u32> {
+    Grid2::from_str(map, |c| c.to_digit(10).ok_or("Expected a digit")).unwrap()
+  }
+
+  #[test]
+  fn index_and_get() {
+    let mut grid = digits("123\n456");
+    assert_eq!(grid.size(), (2, 3));
+    assert_eq!((grid[(1, 2)], grid[0][1]), (6, 2));
+    assert_eq!((grid.get((1, 0)), grid.get((-1, 0)), grid.get((0, 3))), (Some(&4), None, None));
+
+    grid[(0, 0)] = 9;
+    *grid.get_mut((1, 1)).unwrap() = 0;
+    assert_eq!(grid.to_string(), "923\n406");
+  }
+
+  #[test]
+  fn neighbors_stay_on_the_grid() {
+    let grid = Grid2::new(3, 3, '.');
+    assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8((0, 2)).collect::<Vec<_>>(), vec![(0, 1), (1, 2), (1, 1)]);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+  }
+
+  #[test]
+  fn rows_cols_and_rays() {
+    let grid = digits("123\n456\n789");
+    assert_eq!(grid.row(1).collect::<Vec<_>>(), vec![&4, &5, &6]);
+    assert_eq!(grid.col(2).collect::<Vec<_>>(), vec![&3, &6, &9]);
+    assert_eq!(grid.ray((2, 2), (-1, -1)).map(|pos| grid[pos]).collect::<Vec<_>>(), vec![5, 1]);
+    assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+    assert_eq!(grid.positions().filter(|&pos| grid[pos].is_multiple_of(2)).collect::<Vec<_>>(), vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+    assert_eq!(grid.map(|&d| d * 2).iter().sum::<u32>(), 90);
+  }
+
+  #[test]
+  fn parse_errors_have_positions() {
+    let err = Grid2::from_str("#.\n.x", |c| match c {
+      '#' => Ok(true),
+      '.' => Ok(false),
+      _ => Err(format!["Unexpected '{c}'"])
+    }).unwrap_err();
+    assert_eq!((err.line, err.column), (Some(2), Some(2)));
+  }
+}
@@ -0,0 +1,179 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::str::FromStr;
+use crate::error::InputError;
+
+/// One group of numbers: where it starts, how many there are, and their sum, min and max.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group<T> {
+  /// The 1-based line number of the group's first number.
+  pub first_line: usize,
+  pub count: usize,
+  pub sum: T,
+  pub min: T,
+  pub max: T
+}
+
+impl<T: Copy + Ord + Add<Output = T>> Group<T> {
+  fn new(first_line: usize, value: T) -> Self {
+    Group { first_line, count: 1, sum: value, min: value, max: value }
+  }
+
+  fn add(self, value: T) -> Self {
+    Group { count: self.count + 1, sum: self.sum + value, min: self.min.min(value), max: self.max.max(value), ..self }
+  }
+}
+
+/// The groups of numbers in blank-line-separated input with one number per line, like day 1's elves.
+/// Reads a line at a time, so the whole input never has to be in memory: `Groups::new(stdin().lock())`
+/// works as well as `Groups::new(input.text().as_bytes())`.
+///
+/// Lines are trimmed at the end like [`Input`](crate::input::Input)'s, so a line of just spaces
+/// counts as blank. A number that doesn't parse is an error on its line.
+pub struct Groups<R, T> {
+  reader: R,
+  // The number of lines read so far.
+  line: usize,
+  buf: String,
+  values: PhantomData<T>
+}
+
+impl<R: BufRead, T> Groups<R, T> {
+  pub fn new(reader: R) -> Self {
+    Groups { reader, line: 0, buf: String::new(), values: PhantomData }
+  }
+}
+
+impl<R: BufRead, T: FromStr + Copy + Ord + Add<Output = T>> Iterator for Groups<R, T> where T::Err: Into<InputError> {
+  type Item = Result<Group<T>, InputError>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut group: Option<Group<T>> = None;
+    loop {
+      self.buf.clear();
+      match self.reader.read_line(&mut self.buf) {
+        Ok(0) => return group.map(Ok),
+        Ok(_) => self.line += 1,
+        Err(err) => return Some(Err(InputError::new(format!["Couldn't read line {}: {err}", self.line + 1])))
+      }
+      let line = self.buf.trim_end();
+      if line.is_empty() {
+        match group {
+          Some(group) => return Some(Ok(group)),
+          None => continue
+        }
+      }
+      let value = match line.parse::<T>() {
+        Ok(value) => value,
+        Err(err) => return Some(Err(err.into().on_line(self.line, line)))
+      };
+      group = Some(match group {
+        Some(group) => group.add(value),
+        None => Group::new(self.line, value)
+      });
+    }
+  }
+}
+
+/// The `k` largest values pushed so far. Only those are kept, so it takes `O(k)` memory however many
+/// values there are.
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+  k: usize,
+  // A min-heap, so the smallest kept value is the one to replace.
+  heap: BinaryHeap<Reverse<T>>
+}
+
+impl<T: Ord> TopK<T> {
+  pub fn new(k: usize) -> Self {
+    TopK { k, heap: BinaryHeap::with_capacity(k + 1) }
+  }
+
+  pub fn push(&mut self, value: T) {
+    if self.heap.len() < self.k {
+      self.heap.push(Reverse(value));
+    } else if self.heap.peek().is_some_and(|Reverse(smallest)| *smallest < value) {
+      self.heap.pop();
+      self.heap.push(Reverse(value));
+    }
+  }
+
+  /// How many values are kept: `k`, or fewer if fewer have been pushed.
+  pub fn len(&self) -> usize {
+    self.heap.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.heap.is_empty()
+  }
+
+  /// The smallest of the values kept, which a value has to beat to be kept once there are `k`.
+  pub fn min(&self) -> Option<&T> {
+    self.heap.peek().map(|Reverse(smallest)| smallest)
+  }
+
+  /// The values kept, largest first.
+  pub fn into_sorted_vec(self) -> Vec<T> {
+    self.heap.into_sorted_vec().into_iter().map(|Reverse(value)| value).collect()
+  }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+  fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+    values.into_iter().for_each(|value| self.push(value));
+  }
+}
+
+/// The `k` largest group sums in `reader`, largest first. This is the streaming way in: it reads a
+/// line at a time and keeps only the `k` sums, so memory doesn't grow with the input (see day 1's
+/// `main`).
+pub fn top_sums<T: FromStr + Copy + Ord + Add<Output = T>>(reader: impl BufRead, k: usize) -> Result<Vec<T>, InputError> where T::Err: Into<InputError> {
+  let mut top = TopK::new(k);
+  for group in Groups::new(reader) {
+    top.push(group?.sum);
+  }
+  Ok(top.into_sorted_vec())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+  #[test]
+  fn group_stats() {
+    let groups: Vec<Group<i32>> = Groups::new(SAMPLE.as_bytes()).collect::<Result<_, _>>().unwrap();
+    assert_eq!(groups.len(), 5);
+    assert_eq!(groups[0], Group { first_line: 1, count: 3, sum: 6000, min: 1000, max: 3000 });
+    assert_eq!(groups[3], Group { first_line: 10, count: 3, sum: 24000, min: 7000, max: 9000 });
+    assert_eq!(groups[4], Group { first_line: 14, count: 1, sum: 10000, min: 10000, max: 10000 });
+  }
+
+  #[test]
+  fn extra_blank_lines_and_crlf() {
+    let groups: Vec<Group<u32>> = Groups::new("\r\n\r\n1\r\n2\r\n  \r\n\r\n3".as_bytes()).collect::<Result<_, _>>().unwrap();
+    assert_eq!(groups.iter().map(|group| (group.first_line, group.sum)).collect::<Vec<_>>(), vec![(3, 3), (7, 3)]);
+  }
+
+  #[test]
+  fn bad_number() {
+    let err = Groups::<_, i32>::new("1\n\n2\nx3\n".as_bytes()).find_map(Result::err).unwrap();
+    assert_eq!((err.line, err.text.as_deref()), (Some(4), Some("x3")));
+  }
+
+  #[test]
+  fn top_k() {
+    assert_eq!(top_sums::<i32>(SAMPLE.as_bytes(), 1), Ok(vec![24000]));
+    assert_eq!(top_sums::<i32>(SAMPLE.as_bytes(), 3), Ok(vec![24000, 11000, 10000]));
+    assert_eq!(top_sums::<i32>(SAMPLE.as_bytes(), 10), Ok(vec![24000, 11000, 10000, 6000, 4000]));
+    assert_eq!(top_sums::<i32>(SAMPLE.as_bytes(), 0), Ok(vec![]));
+
+    let mut top = TopK::new(2);
+    top.extend([3, 1, 4, 1, 5, 9, 2, 6]);
+    assert_eq!((top.len(), top.min()), (2, Some(&6)));
+  }
+}
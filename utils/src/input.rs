@@ -0,0 +1,236 @@
+use std::io::Read;
+use std::str::FromStr;
+use crate::error::InputError;
+use crate::grid2::Grid2;
+
+// What to call stdin in error messages.
+const STDIN: &str = "<stdin>";
+
+/// Puzzle input, read from a file, stdin or a string, with line endings normalized.
+///
+/// CRLF becomes LF, trailing whitespace is trimmed from every line and trailing blank lines are
+/// dropped, so a file saved with a final newline (or on Windows) parses the same as one without.
+/// Leading whitespace is kept, since some maps (e.g. day 22's) are indented.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+  name: Option<String>,
+  text: String
+}
+
+impl Input {
+  /// Read the file at `path`, or stdin if `path` is `-`.
+  pub fn load(path: &str) -> Result<Input, InputError> {
+    if path == "-" {
+      return Self::stdin();
+    }
+    let text = std::fs::read_to_string(path).map_err(|err| InputError::new(format!["Couldn't read file: {err}"]).in_file(path))?;
+    Ok(Input { name: Some(path.to_string()), ..Input::from(text.as_str()) })
+  }
+
+  pub fn stdin() -> Result<Input, InputError> {
+    let mut text = String::new();
+    std::io::stdin().read_to_string(&mut text).map_err(|err| InputError::new(err).in_file(STDIN))?;
+    Ok(Input { name: Some(STDIN.to_string()), ..Input::from(text.as_str()) })
+  }
+
+  /// Where the input came from, for error messages. Embedded strings have no name.
+  pub fn name(&self) -> Option<&str> {
+    self.name.as_deref()
+  }
+
+  /// Add where the input came from to an error found while parsing it, if that's known.
+  pub fn locate(&self, err: InputError) -> InputError {
+    match &self.name {
+      Some(name) => err.in_file(name),
+      None => err
+    }
+  }
+
+  /// The whole normalized input.
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+
+  pub fn lines(&self) -> impl Iterator<Item = &str> {
+    self.text.lines()
+  }
+
+  /// Parse each line with `parse`, adding the line number and text to any error.
+  pub fn parse_lines<'a, T, E: Into<InputError>>(&'a self, mut parse: impl FnMut(&'a str) -> Result<T, E>) -> Result<Vec<T>, InputError> {
+    self.lines()
+      .enumerate()
+      .map(|(i, line)| parse(line).map_err(|err| err.into().on_line(i + 1, line)))
+      .collect()
+  }
+
+  /// Groups of lines separated by blank lines, e.g. day 1's elves or day 11's monkeys.
+  pub fn blocks(&self) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut current: Option<Block> = None;
+    for (i, line) in self.lines().enumerate() {
+      match (&mut current, line.is_empty()) {
+        (Some(block), false) => block.lines.push(line),
+        (None, false) => current = Some(Block { first_line: i + 1, lines: vec![line] }),
+        (_, true) => blocks.extend(current.take())
+      }
+    }
+    blocks.extend(current);
+    blocks
+  }
+
+  /// A rectangular grid of cells, one row per line, with `cell` parsing each character.
+  pub fn grid<T, E: Into<InputError>>(&self, mut cell: impl FnMut(char) -> Result<T, E>) -> Result<Grid2<T>, InputError> {
+    let width = self.lines().next().map(|line| line.chars().count()).ok_or(InputError::new("Empty input"))?;
+    let rows = self.parse_lines(|line| {
+      if line.chars().count() != width {
+        return Err(InputError::new(format!["Expected {width} cells in every row"]));
+      }
+      line.chars().enumerate().map(|(i, c)| cell(c).map_err(|err| InputError { column: Some(i + 1), ..err.into() })).collect()
+    })?;
+    Ok(Grid2::from_rows(rows))
+  }
+
+  /// Every integer in the input, in order, whatever separates them: commas, spaces, newlines or
+  /// words (`"x=-3, y=12"` gives -3 and 12). A `-` right after a digit is a separator rather than a
+  /// sign, so ranges like `"2-4"` give 2 and 4.
+  pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, InputError> where T::Err: Into<InputError> {
+    let mut ints = vec![];
+    for (i, line) in self.lines().enumerate() {
+      for (start, number) in find_ints(line) {
+        let int = number.parse::<T>().map_err(|err| InputError { column: Some(start + 1), ..err.into() }.on_line(i + 1, line))?;
+        ints.push(int);
+      }
+    }
+    Ok(ints)
+  }
+}
+
+/// An embedded input, e.g. a puzzle example in a test.
+impl From<&str> for Input {
+  fn from(text: &str) -> Self {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    Input { name: None, text: lines.join("\n").trim_end_matches('\n').to_string() }
+  }
+}
+
+/// A run of non-blank lines from an [`Input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+  /// The 1-based line number of the block's first line in the whole input.
+  pub first_line: usize,
+  pub lines: Vec<&'a str>
+}
+
+impl<'a> Block<'a> {
+  /// Parse each line with `parse`, numbering errors by their line in the whole input.
+  pub fn parse_lines<T, E: Into<InputError>>(&self, mut parse: impl FnMut(&'a str) -> Result<T, E>) -> Result<Vec<T>, InputError> {
+    self.lines
+      .iter()
+      .enumerate()
+      .map(|(i, &line)| parse(line).map_err(|err| err.into().on_line(self.first_line + i, line)))
+      .collect()
+  }
+
+  /// For errors found while parsing the block's lines some other way, numbered from 1 within the
+  /// block: make the line number relative to the whole input.
+  pub fn locate(&self, err: InputError) -> InputError {
+    err.after_lines(self.first_line - 1)
+  }
+}
+
+// The byte offset and text of each integer in `line`.
+fn find_ints(line: &str) -> Vec<(usize, &str)> {
+  let bytes = line.as_bytes();
+  let mut ints = vec![];
+  let mut i = 0;
+  while i < bytes.len() {
+    let after_digit = i > 0 && bytes[i - 1].is_ascii_digit();
+    let signed = bytes[i] == b'-' && !after_digit && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+    if !bytes[i].is_ascii_digit() && !signed {
+      i += 1;
+      continue;
+    }
+    let start = i;
+    i += 1;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+      i += 1;
+    }
+    ints.push((start, &line[start..i]));
+  }
+  ints
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn normalize_line_endings() {
+    let input = Input::from("1\r\n2  \r\n\r\n3\n\n\n");
+    assert_eq!(input.text(), "1\n2\n\n3");
+    assert_eq!(input.lines().collect::<Vec<_>>(), vec!["1", "2", "", "3"]);
+    assert_eq!(Input::from("  indented\n").text(), "  indented");
+    assert_eq!(Input::from("").lines().count(), 0);
+  }
+
+  #[test]
+  fn trailing_newline_parses() {
+    let numbers = Input::from("1\n-2\n3\n").parse_lines(str::parse::<i32>).unwrap();
+    assert_eq!(numbers, vec![1, -2, 3]);
+  }
+
+  #[test]
+  fn parse_lines_numbers_errors() {
+    let err = Input::from("1\n2\nthree").parse_lines(str::parse::<i32>).unwrap_err();
+    assert_eq!((err.line, err.text.as_deref()), (Some(3), Some("three")));
+    assert_eq!(err.message, "Invalid number: invalid digit found in string");
+  }
+
+  #[test]
+  fn blocks_know_their_lines() {
+    let input = Input::from("a\nb\n\n\nc\n\nd\ne\n");
+    let blocks = input.blocks();
+    assert_eq!(blocks, vec![
+      Block { first_line: 1, lines: vec!["a", "b"] },
+      Block { first_line: 5, lines: vec!["c"] },
+      Block { first_line: 7, lines: vec!["d", "e"] }
+    ]);
+
+    let err = blocks[2].parse_lines(|line| if line == "e" { Err("Bad") } else { Ok(line) }).unwrap_err();
+    assert_eq!((err.line, err.text.as_deref()), (Some(8), Some("e")));
+    assert_eq!(blocks[1].locate(InputError::new("Bad").on_line(1, "c")).line, Some(5));
+  }
+
+  #[test]
+  fn grid_is_rectangular() {
+    let digits = |c: char| c.to_digit(10).ok_or("Expected a digit");
+    assert_eq!(Input::from("12\n34\n").grid(digits).unwrap(), Grid2::from_rows(vec![vec![1, 2], vec![3, 4]]));
+
+    let err = Input::from("12\n3").grid(digits).unwrap_err();
+    assert_eq!((err.line, err.message.as_str()), (Some(2), "Expected 2 cells in every row"));
+
+    let err = Input::from("12\n3x").grid(digits).unwrap_err();
+    assert_eq!((err.line, err.column), (Some(2), Some(2)));
+  }
+
+  #[test]
+  fn ints_anywhere() {
+    assert_eq!(Input::from("16,1,2\n0,4\n").ints::<i32>().unwrap(), vec![16, 1, 2, 0, 4]);
+    assert_eq!(Input::from("Sensor at x=-3, y=12: beacon").ints::<i64>().unwrap(), vec![-3, 12]);
+    assert_eq!(Input::from("2-4,6-8").ints::<u32>().unwrap(), vec![2, 4, 6, 8]);
+
+    let err = Input::from("1\n2 300").ints::<u8>().unwrap_err();
+    assert_eq!((err.line, err.column), (Some(2), Some(3)));
+  }
+
+  #[test]
+  fn load_names_the_file() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    let input = Input::load(path).unwrap();
+    assert_eq!(input.name(), Some(path));
+    assert!(input.lines().next().unwrap().starts_with("[package]"));
+
+    let err = Input::load("no-such-file.txt").unwrap_err();
+    assert_eq!(err.file.as_deref(), Some("no-such-file.txt"));
+  }
+}
@@ -1,4 +1,8 @@
-use std::io::{self, BufRead};
+pub mod error;
+use error::InputError;
+
+pub mod input;
+use input::Input;
 
 pub mod load;
 use load::*;
@@ -9,35 +13,37 @@ use signals_from_day8::Puzzle;
 pub mod coordinates;
 use coordinates::Line;
 
+pub mod bitset;
+pub mod fetch;
+pub mod generate;
+pub mod grid2;
+pub mod groups;
+pub mod memo;
+pub mod render;
+pub mod search;
+pub mod vector;
+
 pub mod rps;
 pub mod ranges;
 pub mod terminal_cmds;
+pub mod solution;
+pub mod parallel;
+pub mod trace;
+pub mod validate;
 
-pub fn load_comma_separated_ints() -> Vec<i32> {
-  io::stdin()
-    .lock()
-    .lines()
-    .map(
-        |line| parse_comma_separated(&line.unwrap())
-    )
-    .next()
-    .unwrap()
+/// The comma-separated ints on the first line of `input`.
+pub fn load_comma_separated_ints(input: &Input) -> Result<Vec<i32>, InputError> {
+  let line = input.lines().next().ok_or_else(|| input.locate(InputError::new("Empty input")))?;
+  parse_comma_separated(line).map_err(|err| input.locate(err.on_line(1, line)))
 }
 
-pub fn load_day8_2021_puzzle() -> impl Iterator<Item = Puzzle> {
-  io::stdin()
-    .lock()
-    .lines()
-    .map(
-      |line| parse_day8_2021_puzzle(&line.unwrap())
-    )
+pub fn load_day8_2021_puzzle(input: &Input) -> Result<Vec<Puzzle>, InputError> {
+  input.parse_lines(parse_day8_2021_puzzle).map_err(|err| input.locate(err))
 }
 
-pub fn load_line2d() -> impl Iterator<Item = Line> {
-  // Don't construct a new parser for every single line; have the closure take ownership of the parser.
+pub fn load_line2d(input: &Input) -> Result<Vec<Line>, InputError> {
   let parser = coord_2d_parser::Line2dParser::new();
-  let parser_fn = move |line: Result<String, _>| parser.parse(&line.unwrap()).unwrap();
-  io::stdin().lock().lines().map(parser_fn)
+  input.parse_lines(|line| parser.parse(line)).map_err(|err| input.locate(err))
 }
 
 /// Take a string of input separated by a particular separator and create a vector of parsed values.
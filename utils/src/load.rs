@@ -1,25 +1,26 @@
 use lalrpop_util::*;
 lalrpop_mod!(pub load_parser);
 lalrpop_mod!(pub range_parser);
-lalrpop_mod!(pub rps_parser);
+lalrpop_mod!(pub strategy_parser);
 lalrpop_mod!(pub coord_2d_parser);
 lalrpop_mod!(pub signals_parser);
 lalrpop_mod!(pub crane_parser);
 lalrpop_mod!(pub terminal_parser);
 
+use crate::error::InputError;
 use crate::signals_from_day8::{Puzzle};
 
 #[cfg(test)]
 use crate::coordinates::{Line};
 
-pub fn parse_comma_separated(line: &str) -> Vec<i32> {
+pub fn parse_comma_separated(line: &str) -> Result<Vec<i32>, InputError> {
   let parser = load_parser::CommaSepInt32sParser::new();
-  parser.parse(line).unwrap()
+  Ok(parser.parse(line)?)
 }
 
-pub fn parse_day8_2021_puzzle(line: &str) -> Puzzle {
+pub fn parse_day8_2021_puzzle(line: &str) -> Result<Puzzle, InputError> {
   let parser = signals_parser::PuzzleParser::new();
-  parser.parse(line).unwrap()
+  Ok(parser.parse(line)?)
 }
 
 #[cfg(test)]
@@ -30,14 +31,15 @@ pub fn parse_line2d(line: &str) -> Line {
 
 #[test]
 fn test_parse_line() {
-  assert_eq!(parse_comma_separated("1,1,2,3,5,8,13"), vec![1,1,2,3,5,8,13]);
+  assert_eq!(parse_comma_separated("1,1,2,3,5,8,13"), Ok(vec![1,1,2,3,5,8,13]));
+  assert_eq!(parse_comma_separated("1,1,x").unwrap_err().column, Some(5));
 }
 
 #[test]
 fn test_day8_2021_lines() {
   let input = "acedgfb cdfbe ab | cdbaf";
   let expected = "([{'a', 'b', 'c', 'd', 'e', 'f', 'g'}, {'b', 'c', 'd', 'e', 'f'}, {'a', 'b'}], [{'a', 'b', 'c', 'd', 'f'}])";
-  assert_eq!(format!["{:?}", parse_day8_2021_puzzle(input)], expected);
+  assert_eq!(format!["{:?}", parse_day8_2021_puzzle(input).unwrap()], expected);
 }
 
 #[test]
@@ -53,36 +55,39 @@ fn test_poly() {
 }
 
 #[cfg(test)]
-mod rps_parser_tests {
-  use super::rps_parser::{RpsHandParser, RpsHandOutcomeParser};
-  use crate::rps::{Play, Outcome};
+mod strategy_parser_tests {
+  use super::strategy_parser::{RoundParser, RoundOutcomeParser};
+  use crate::rps::{Outcome, Symbols};
 
   #[test]
-  fn rps_parser() {
-    let parser = RpsHandParser::new();
-    assert_eq!(parser.parse("A X").unwrap(), (Play::Rock, Play::Rock));
-    assert_eq!(parser.parse("B Y").unwrap(), (Play::Paper, Play::Paper));
-    assert_eq!(parser.parse("C Z").unwrap(), (Play::Scissors, Play::Scissors));
+  fn puzzle_symbols() {
+    let symbols = Symbols::puzzle();
+    assert_eq!(RoundParser::new().parse(&symbols, "A Y"), Ok((0, 1)));
+    assert_eq!(RoundOutcomeParser::new().parse(&symbols, "C Z"), Ok((2, Outcome::Win)));
+    assert!(RoundParser::new().parse(&symbols, "X A").is_err());
   }
 
   #[test]
-  fn rps_outcome_parser() {
-    let parser = RpsHandOutcomeParser::new();
-    assert_eq!(parser.parse("A X").unwrap(), (Play::Rock, Outcome::Lose));
+  fn own_symbols() {
+    let lizard_spock = ["rock", "spock", "paper", "lizard", "scissors"].map(String::from);
+    let symbols = Symbols { theirs: lizard_spock.to_vec(), mine: lizard_spock.to_vec(), outcomes: ["-", "=", "+"].map(String::from) };
+    assert_eq!(RoundParser::new().parse(&symbols, "lizard spock"), Ok((3, 1)));
+    assert_eq!(RoundOutcomeParser::new().parse(&symbols, "spock +"), Ok((1, Outcome::Win)));
+    assert!(RoundParser::new().parse(&symbols, "lizard well").is_err());
   }
 }
 
 
 #[cfg(test)]
 pub mod range_load {
-  use crate::ranges::Range;
+  use crate::ranges::ClosedRange;
 
   use super::*;
 
   #[test]
   pub fn load_ranges() {
     let parser = range_parser::RangePairParser::new();
-    assert_eq!(parser.parse("2-4,6-8").unwrap(), (Range::new(2,4), Range::new(6,8)));
+    assert_eq!(parser.parse("2-4,6-8").unwrap(), (ClosedRange::new(2,4), ClosedRange::new(6,8)));
   }
 }
 
@@ -116,3 +121,180 @@ pub mod term_load {
     assert_eq!(parser.parse("$ cd csmqbhjv").unwrap(), Command::MakeDir);
   }
 }
+
+// Round trips: make up a value, print it the way the puzzles do, and parse it back. Numbers just past
+// the edge of their type have to come back as the grammar's "can't be parsed" error, not a panic, and
+// no input at all should make a parser panic.
+#[cfg(test)]
+mod properties {
+  use super::*;
+  use proptest::prelude::*;
+  use crate::coordinates::{Coord, Line};
+  use crate::ranges::ClosedRange;
+  use crate::rps::{Outcome, Symbols};
+  use crate::signals_from_day8::Signal;
+  use crate::terminal_cmds::Command;
+  use lalrpop_util::ParseError;
+
+  fn is_unparsable_number<T, L, Tok, E>(result: Result<T, ParseError<L, Tok, E>>) -> bool {
+    matches!(result, Err(ParseError::User { .. }))
+  }
+
+  fn join<T: ToString>(items: &[T], separator: &str) -> String {
+    items.iter().map(T::to_string).collect::<Vec<_>>().join(separator)
+  }
+
+  fn coord() -> impl Strategy<Value = Coord> {
+    (any::<i32>(), any::<i32>()).prop_map(|(x, y)| Coord::new(x, y, 0))
+  }
+
+  fn print_coord(c: &Coord) -> String {
+    format!["{},{}", c.x, c.y]
+  }
+
+  // Numbers that fit in an i64 but not an i32.
+  fn past_i32() -> impl Strategy<Value = i64> {
+    prop_oneof![i64::MIN..i32::MIN as i64, i32::MAX as i64 + 1..=i64::MAX]
+  }
+
+  fn command() -> impl Strategy<Value = (String, Command)> {
+    prop_oneof![
+      Just(("$ cd /".to_string(), Command::MakeDir)),
+      "[a-z]{1,8}".prop_map(|dir| (format!["$ cd {dir}"], Command::MakeDir)),
+      Just(("$ cd ..".to_string(), Command::FinishDir)),
+      Just(("$ ls".to_string(), Command::Ignore)),
+      "[a-z]{1,8}".prop_map(|dir| (format!["dir {dir}"], Command::Ignore)),
+      (any::<u64>(), "[a-z]{1,8}(\\.[a-z]{1,3})?").prop_map(|(size, file)| (format!["{size} {file}"], Command::FileSize(size)))
+    ]
+  }
+
+  // The parsers are built once per test, outside `proptest!`, because building one (compiling its
+  // regexes) takes far longer than parsing a line.
+
+  #[test]
+  fn comma_separated_ints() {
+    let parser = load_parser::CommaSepInt32sParser::new();
+    proptest!(|(ints in prop::collection::vec(any::<i32>(), 1..20), space in prop::bool::ANY)| {
+      let line = join(&ints, if space { ", " } else { "," });
+      prop_assert_eq!(parser.parse(&line), Ok(ints));
+    });
+    proptest!(|(ints in prop::collection::vec(any::<i32>(), 0..5), big in past_i32())| {
+      let line = join(&ints.iter().map(|&i| i as i64).chain([big]).collect::<Vec<_>>(), ",");
+      prop_assert!(is_unparsable_number(parser.parse(&line)));
+    });
+  }
+
+  #[test]
+  fn line2d() {
+    let parser = coord_2d_parser::Line2dParser::new();
+    proptest!(|(s in coord(), e in coord())| {
+      let line = format!["{} -> {}", print_coord(&s), print_coord(&e)];
+      prop_assert_eq!(format!["{:?}", parser.parse(&line).unwrap()], format!["{:?}", Line::new(s, e)]);
+    });
+  }
+
+  #[test]
+  fn poly() {
+    let parser = coord_2d_parser::PolyParser::new();
+    proptest!(|(coords in prop::collection::vec(coord(), 1..10))| {
+      let line = coords.iter().map(print_coord).collect::<Vec<_>>().join(" -> ");
+      prop_assert_eq!(parser.parse(&line), Ok(coords));
+    });
+    proptest!(|(x in past_i32(), y in any::<i32>())| {
+      let (first, second) = (format!["{x},{y}"], format!["{y},{x}"]);
+      prop_assert!(is_unparsable_number(parser.parse(&first)));
+      prop_assert!(is_unparsable_number(parser.parse(&second)));
+    });
+  }
+
+  #[test]
+  fn range_pair() {
+    let parser = range_parser::RangePairParser::new();
+    proptest!(|(a in any::<u32>(), b in any::<u32>(), c in any::<u32>(), d in any::<u32>())| {
+      let line = format!["{a}-{b},{c}-{d}"];
+      prop_assert_eq!(parser.parse(&line), Ok((ClosedRange::new(a, b), ClosedRange::new(c, d))));
+    });
+    proptest!(|(a in any::<u32>(), big in u32::MAX as u64 + 1..=u64::MAX)| {
+      let line = format!["{a}-{big},{a}-{a}"];
+      prop_assert!(is_unparsable_number(parser.parse(&line)));
+    });
+  }
+
+  #[test]
+  fn crane_move() {
+    let parser = crane_parser::MoveParser::new();
+    proptest!(|(count in any::<u32>(), from in any::<u32>(), to in any::<u32>())| {
+      let line = format!["move {count} from {from} to {to}"];
+      prop_assert_eq!(parser.parse(&line), Ok((count, from, to)));
+    });
+    proptest!(|(count in u32::MAX as u64 + 1..=u64::MAX, from in any::<u32>())| {
+      let line = format!["move {count} from {from} to 1"];
+      prop_assert!(is_unparsable_number(parser.parse(&line)));
+    });
+  }
+
+  #[test]
+  fn strategy_rounds() {
+    // Symbols can be any run of non-space characters, as long as they're different within a column.
+    let column = |moves| prop::collection::hash_set("[!-~]{1,6}", moves).prop_map(Vec::from_iter);
+    let symbols = (column(1..8), column(1..8), column(3..4)).prop_map(|(theirs, mine, outcomes)| {
+      Symbols { theirs, mine, outcomes: [outcomes[0].clone(), outcomes[1].clone(), outcomes[2].clone()] }
+    });
+    let (round, round_outcome) = (strategy_parser::RoundParser::new(), strategy_parser::RoundOutcomeParser::new());
+    proptest!(|(symbols in symbols, theirs in any::<prop::sample::Index>(), mine in any::<prop::sample::Index>())| {
+      let (theirs, mine) = (theirs.index(symbols.theirs.len()), mine.index(symbols.mine.len()));
+      let line = format!["{} {}", symbols.theirs[theirs], symbols.mine[mine]];
+      prop_assert_eq!(round.parse(&symbols, &line), Ok((theirs, mine)));
+      for (outcome, symbol) in [Outcome::Lose, Outcome::Draw, Outcome::Win].into_iter().zip(&symbols.outcomes) {
+        let line = format!["{} {symbol}", symbols.theirs[theirs]];
+        prop_assert_eq!(round_outcome.parse(&symbols, &line), Ok((theirs, outcome)));
+      }
+    });
+  }
+
+  #[test]
+  fn signals() {
+    let parser = signals_parser::PuzzleParser::new();
+    let signals = |count| prop::collection::vec(prop::collection::btree_set(prop::char::range('a', 'g'), 1..=7), count);
+    proptest!(|(transients in signals(1..=10), readout in signals(1..=4))| {
+      let print = |signals: &Vec<Signal>| signals.iter().map(|s| s.iter().collect::<String>()).collect::<Vec<_>>().join(" ");
+      let line = format!["{} | {}", print(&transients), print(&readout)];
+      prop_assert_eq!(parser.parse(&line), Ok((transients, readout)));
+    });
+  }
+
+  #[test]
+  fn terminal_commands() {
+    let parser = terminal_parser::CommandParser::new();
+    proptest!(|((line, command) in command())| {
+      prop_assert_eq!(parser.parse(&line), Ok(command));
+    });
+    proptest!(|(size in u64::MAX as u128 + 1..=u128::MAX, file in "[a-z]{1,8}")| {
+      let line = format!["{size} {file}"];
+      prop_assert!(is_unparsable_number(parser.parse(&line)));
+    });
+  }
+
+  #[test]
+  fn no_input_panics() {
+    let comma_separated = load_parser::CommaSepInt32sParser::new();
+    let line2d = coord_2d_parser::Line2dParser::new();
+    let poly = coord_2d_parser::PolyParser::new();
+    let range_pair = range_parser::RangePairParser::new();
+    let crane = crane_parser::MoveParser::new();
+    let (round, round_outcome, symbols) = (strategy_parser::RoundParser::new(), strategy_parser::RoundOutcomeParser::new(), Symbols::puzzle());
+    let signals = signals_parser::PuzzleParser::new();
+    let terminal = terminal_parser::CommandParser::new();
+    proptest!(|(line in "\\PC{0,40}")| {
+      let _ = comma_separated.parse(&line);
+      let _ = line2d.parse(&line);
+      let _ = poly.parse(&line);
+      let _ = range_pair.parse(&line);
+      let _ = crane.parse(&line);
+      let _ = round.parse(&symbols, &line);
+      let _ = round_outcome.parse(&symbols, &line);
+      let _ = signals.parse(&line);
+      let _ = terminal.parse(&line);
+    });
+  }
+}
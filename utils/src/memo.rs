@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// How well a `Memo` is doing: lookups that found a value, lookups that didn't, and values thrown
+/// out to stay under its capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+  pub hits: u64,
+  pub misses: u64,
+  pub evictions: u64
+}
+
+impl Stats {
+  /// The fraction of lookups that were hits (0 if there weren't any).
+  pub fn hit_rate(&self) -> f64 {
+    match self.hits + self.misses {
+      0 => 0.0,
+      lookups => self.hits as f64 / lookups as f64
+    }
+  }
+}
+
+impl Display for Stats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} hits, {} misses ({:.1}% hit), {} evicted", self.hits, self.misses, self.hit_rate() * 100.0, self.evictions)
+  }
+}
+
+// Stands in for "no entry" at either end of the recency list.
+const NONE: usize = usize::MAX;
+
+struct Entry<K, V> {
+  key: K,
+  value: V,
+  // The entries used just before and just after this one. Only kept up when bounded.
+  older: usize,
+  newer: usize
+}
+
+/// Values already worked out, keyed by the state they were worked out for. Key it with a small
+/// `Hash` struct of just what the answer depends on (like `(valve, minutes_left, opened)`) rather
+/// than a formatted string, which costs more to build than a lot of answers do to find.
+///
+/// `Memo::new()` keeps everything. `Memo::bounded(n)` keeps at most `n` values, throwing out the
+/// least recently used one to make room. Keeping track of that is O(1) and doesn't allocate: the
+/// entries live in a `Vec` and are linked into a list by index, newest to oldest.
+pub struct Memo<K, V> {
+  index: HashMap<K, usize>,
+  entries: Vec<Entry<K, V>>,
+  newest: usize,
+  oldest: usize,
+  capacity: Option<usize>,
+  stats: Stats
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+  pub fn new() -> Self {
+    Memo { index: HashMap::new(), entries: vec![], newest: NONE, oldest: NONE, capacity: None, stats: Stats::default() }
+  }
+
+  /// A memo that keeps at most `capacity` values.
+  pub fn bounded(capacity: usize) -> Self {
+    assert!(capacity > 0, "A memo has to keep at least one value");
+    Memo { capacity: Some(capacity), ..Self::new() }
+  }
+
+  /// The value for `key`, if it's been worked out (and not evicted). Counts as a hit or a miss.
+  pub fn get(&mut self, key: &K) -> Option<V> {
+    let Some(&i) = self.index.get(key) else {
+      self.stats.misses += 1;
+      return None;
+    };
+    self.stats.hits += 1;
+    if self.capacity.is_some() {
+      self.unlink(i);
+      self.link_newest(i);
+    }
+    Some(self.entries[i].value.clone())
+  }
+
+  pub fn insert(&mut self, key: K, value: V) {
+    if let Some(&i) = self.index.get(&key) {
+      self.entries[i].value = value;
+      if self.capacity.is_some() {
+        self.unlink(i);
+        self.link_newest(i);
+      }
+      return;
+    }
+
+    let i = if self.capacity == Some(self.entries.len()) {
+      // Full, so reuse the least recently used entry's slot.
+      let i = self.oldest;
+      self.unlink(i);
+      self.index.remove(&self.entries[i].key);
+      self.stats.evictions += 1;
+      self.entries[i].key = key.clone();
+      self.entries[i].value = value;
+      i
+    } else {
+      self.entries.push(Entry { key: key.clone(), value, older: NONE, newer: NONE });
+      self.entries.len() - 1
+    };
+    if self.capacity.is_some() {
+      self.link_newest(i);
+    }
+    self.index.insert(key, i);
+  }
+
+  fn unlink(&mut self, i: usize) {
+    let Entry { older, newer, .. } = self.entries[i];
+    match older {
+      NONE => self.oldest = newer,
+      older => self.entries[older].newer = newer
+    }
+    match newer {
+      NONE => self.newest = older,
+      newer => self.entries[newer].older = older
+    }
+  }
+
+  fn link_newest(&mut self, i: usize) {
+    self.entries[i].older = self.newest;
+    self.entries[i].newer = NONE;
+    match self.newest {
+      NONE => self.oldest = i,
+      newest => self.entries[newest].newer = i
+    }
+    self.newest = i;
+  }
+
+  /// The value for `key`, working it out with `f` if it hasn't been yet.
+  pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> V {
+    self.recurse(key, |_, _| f())
+  }
+
+  /// The value for `key`, working it out with `f` if it hasn't been yet. `f` gets the memo too, so a
+  /// recursive function can look up (or work out) its subproblems in it, e.g.
+  /// `memo.recurse(n, |memo, &n| if n < 2 { n } else { fib(memo, n - 1) + fib(memo, n - 2) })`.
+  pub fn recurse(&mut self, key: K, f: impl FnOnce(&mut Self, &K) -> V) -> V {
+    if let Some(value) = self.get(&key) {
+      return value;
+    }
+    let value = f(self, &key);
+    self.insert(key, value.clone());
+    value
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  pub fn stats(&self) -> Stats {
+    self.stats
+  }
+
+  /// Forget every value (but not the stats).
+  pub fn clear(&mut self) {
+    self.index.clear();
+    self.entries.clear();
+    self.newest = NONE;
+    self.oldest = NONE;
+  }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn count_hits_and_misses() {
+    let mut memo = Memo::new();
+    assert_eq!(memo.get(&"a"), None);
+    memo.insert("a", 1);
+    assert_eq!(memo.get(&"a"), Some(1));
+    assert_eq!(memo.get_or_insert_with("a", || 2), 1);
+    assert_eq!(memo.get_or_insert_with("b", || 2), 2);
+    assert_eq!(memo.stats(), Stats { hits: 2, misses: 2, evictions: 0 });
+    assert_eq!(memo.stats().to_string(), "2 hits, 2 misses (50.0% hit), 0 evicted");
+  }
+
+  #[test]
+  fn evict_least_recently_used() {
+    let mut memo = Memo::bounded(2);
+    memo.insert(1, 'a');
+    memo.insert(2, 'b');
+    memo.get(&1);
+    memo.insert(3, 'c');
+    assert_eq!((memo.get(&1), memo.get(&2), memo.get(&3)), (Some('a'), None, Some('c')));
+    memo.insert(3, 'C');
+    assert_eq!(memo.len(), 2);
+    memo.insert(4, 'd');
+    assert_eq!((memo.get(&1), memo.get(&3), memo.get(&4)), (None, Some('C'), Some('d')));
+    assert_eq!(memo.stats().evictions, 2);
+  }
+
+  #[derive(Clone, PartialEq, Eq, Hash)]
+  struct Climb {
+    step: u8,
+    left: u8
+  }
+
+  // Ways to climb `left` stairs taking 1 to 3 at a time (and which one we're on, to have a struct key).
+  fn ways(memo: &mut Memo<Climb, u64>, climb: Climb) -> u64 {
+    memo.recurse(climb, |memo, climb| match climb.left {
+      0 => 1,
+      left => (1..=left.min(3)).map(|steps| ways(memo, Climb { step: climb.step + steps, left: left - steps })).sum()
+    })
+  }
+
+  #[test]
+  fn recurse_on_structs() {
+    let mut memo = Memo::new();
+    assert_eq!(ways(&mut memo, Climb { step: 0, left: 30 }), 53798080);
+    assert_eq!(memo.len(), 31);
+    assert!(memo.stats().hit_rate() > 0.5);
+
+    let mut bounded = Memo::bounded(4);
+    assert_eq!(ways(&mut bounded, Climb { step: 0, left: 30 }), 53798080);
+    assert_eq!(bounded.len(), 4);
+  }
+}
+
@@ -0,0 +1,76 @@
+use std::ops::Range;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Whether the `parallel` feature is on. Without it, everything here runs in order on the calling
+/// thread, so the same solver builds either way.
+pub const ENABLED: bool = cfg!(feature = "parallel");
+
+#[cfg(not(feature = "parallel"))]
+const DISABLED: &str = "Built without the parallel feature, so everything runs on one thread";
+
+/// `f` of each of `items`, in order. The calls can happen at the same time on different threads, so
+/// only use it where they don't depend on each other, like day 19's blueprints. Events `f` logs stay
+/// in the caller's span (and so keep its day and part).
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+  #[cfg(feature = "parallel")]
+  {
+    let span = tracing::Span::current();
+    items.par_iter().map(|item| span.in_scope(|| f(item))).collect()
+  }
+  #[cfg(not(feature = "parallel"))]
+  items.iter().map(f).collect()
+}
+
+/// `f` of the first index in `indices` it's `Some` for, like the first of day 15's rows with a gap.
+/// Later indices might be tried at the same time, but the answer is always the first one's.
+pub fn find_map_first<R: Send>(indices: Range<usize>, f: impl Fn(usize) -> Option<R> + Sync + Send) -> Option<R> {
+  #[cfg(feature = "parallel")]
+  {
+    let span = tracing::Span::current();
+    indices.into_par_iter().find_map_first(|i| span.in_scope(|| f(i)))
+  }
+  #[cfg(not(feature = "parallel"))]
+  indices.into_iter().find_map(f)
+}
+
+/// Use `threads` threads (instead of one per core) from now on. It can only be set once, before
+/// anything has run in parallel.
+pub fn set_threads(threads: usize) -> Result<(), String> {
+  #[cfg(feature = "parallel")]
+  return rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().map_err(|err| err.to_string());
+  #[cfg(not(feature = "parallel"))]
+  if threads == 1 { Ok(()) } else { Err(DISABLED.to_string()) }
+}
+
+/// Run `f` with `threads` threads, whatever `set_threads` said, e.g. to time it on one thread.
+pub fn with_threads<R: Send>(threads: usize, f: impl FnOnce() -> R + Send) -> Result<R, String> {
+  #[cfg(feature = "parallel")]
+  return rayon::ThreadPoolBuilder::new().num_threads(threads).build().map(|pool| pool.install(f)).map_err(|err| err.to_string());
+  #[cfg(not(feature = "parallel"))]
+  if threads == 1 { Ok(f()) } else { Err(DISABLED.to_string()) }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn map_keeps_order() {
+    let squares = with_threads(if ENABLED { 4 } else { 1 }, || map(&(0..100).collect::<Vec<u64>>(), |n| n * n)).unwrap();
+    assert_eq!(squares, (0..100).map(|n| n * n).collect::<Vec<u64>>());
+  }
+
+  #[test]
+  fn find_the_first() {
+    let first = with_threads(if ENABLED { 4 } else { 1 }, || find_map_first(0..10_000, |i| (i % 1000 == 999).then_some(i))).unwrap();
+    assert_eq!(first, Some(999));
+    assert_eq!(find_map_first(0..10, |_| None::<usize>), None);
+  }
+
+  #[test]
+  fn threads_need_the_feature() {
+    assert_eq!(with_threads(1, || 7), Ok(7));
+    assert_eq!(with_threads(2, || 7).is_ok(), ENABLED);
+  }
+}
@@ -1,52 +1,482 @@
-use num::traits::One;
+use std::ops::{Add, Sub};
+use num::{CheckedAdd, CheckedSub, One, Zero};
 
+/// What a range's endpoints can be: any integer type.
+pub trait Bound: Ord + Copy + One + Zero + Add<Output = Self> + Sub<Output = Self> + CheckedAdd + CheckedSub {}
+
+impl<T: Ord + Copy + One + Zero + Add<Output = T> + Sub<Output = T> + CheckedAdd + CheckedSub> Bound for T {}
+
+// Whether `b` is the value right after `a`, without adding to or subtracting from either, so that it
+// works at 0 and `T::MAX` too.
+fn just_after<T: Bound>(a: T, b: T) -> bool {
+  a < b && b - a == T::one()
+}
+
+/// The values from `start` to `end`, including both, like `start..=end`. Puzzle inputs like `"2-4"`
+/// usually mean this. Empty if `end < start`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Range<T> {
+pub struct ClosedRange<T> {
   pub start: T,
   pub end: T
 }
 
-impl<T> Range<T>
-where
-  T: Ord + 
-     PartialOrd + 
-     num::One + 
-     Copy +
-     std::ops::Add<Output = T> +
-     std::ops::Sub<Output = T>
-{
-  pub fn new(start: T, end: T) -> Range<T> {
-    Range {
+impl<T: Bound> ClosedRange<T> {
+  pub fn new(start: T, end: T) -> ClosedRange<T> {
+    ClosedRange {
       start,
       end
     }
   }
 
-  pub fn fully_contains(&self, other: &Range<T>) -> bool {
+  /// How many values are in the range. Panics if that's more than `T` can count, like for
+  /// `0..=u32::MAX`; see `checked_len`.
+  pub fn len(&self) -> T {
+    self.checked_len().expect("The range has more values than its type can count")
+  }
+
+  /// How many values are in the range, or `None` if that's more than `T` can count.
+  pub fn checked_len(&self) -> Option<T> {
+    if self.is_empty() { Some(T::zero()) } else { self.end.checked_sub(&self.start)?.checked_add(&One::one()) }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.end < self.start
+  }
+
+  pub fn contains(&self, value: T) -> bool {
+    self.start <= value && value <= self.end
+  }
+
+  pub fn fully_contains(&self, other: &ClosedRange<T>) -> bool {
     other.end <= self.end && other.start >= self.start
   }
 
-  pub fn overlaps(&self, other: &Range<T>) -> bool {
-    other.end >= self.start && other.start <= self.start ||
-    other.start <= self.end && other.start >= self.start
+  pub fn overlaps(&self, other: &ClosedRange<T>) -> bool {
+    self.intersection(other).is_some()
   }
 
-  pub fn adjacent(&self, other: &Range<T>) -> bool
+  pub fn adjacent(&self, other: &ClosedRange<T>) -> bool
   {
-    (other.end + One::one()) == self.start ||
-    (self.end + One::one()) == other.start
+    just_after(other.end, self.start) || just_after(self.end, other.start)
   }
 
-  pub fn combine(&self, other: &Range<T>) -> Option<Range<T>> {
+  /// The one range covering both, if they overlap or are adjacent.
+  pub fn combine(&self, other: &ClosedRange<T>) -> Option<ClosedRange<T>> {
     if self.overlaps(other) || self.adjacent(other) {
-        let combined = Range::new(std::cmp::min(self.start, other.start), std::cmp::max(self.end, other.end));
+        let combined = ClosedRange::new(std::cmp::min(self.start, other.start), std::cmp::max(self.end, other.end));
         return Some(combined);
     }
     None
+  }
+
+  pub fn intersection(&self, other: &ClosedRange<T>) -> Option<ClosedRange<T>> {
+    let overlap = ClosedRange::new(std::cmp::max(self.start, other.start), std::cmp::min(self.end, other.end));
+    (!overlap.is_empty()).then_some(overlap)
+  }
+
+  /// The values before `at`, and the values from `at` on. Either may be `None` if it would be empty.
+  pub fn split_at(&self, at: T) -> (Option<ClosedRange<T>>, Option<ClosedRange<T>>) {
+    if self.is_empty() {
+      (None, None)
+    } else if at <= self.start {
+      (None, Some(*self))
+    } else if self.end < at {
+      (Some(*self), None)
+    } else {
+      // `at` is past `start`, so there's a value before it.
+      (Some(ClosedRange::new(self.start, at - One::one())), Some(ClosedRange::new(at, self.end)))
+    }
+  }
+}
+
+/// The values from `start` up to but not including `end`, like `start..end`. Empty if `end <= start`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HalfOpenRange<T> {
+  pub start: T,
+  pub end: T
+}
+
+impl<T: Bound> HalfOpenRange<T> {
+  pub fn new(start: T, end: T) -> HalfOpenRange<T> {
+    HalfOpenRange {
+      start,
+      end
+    }
+  }
+
+  /// How many values are in the range.
+  pub fn len(&self) -> T {
+    if self.is_empty() { T::zero() } else { self.end - self.start }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.end <= self.start
+  }
+
+  pub fn contains(&self, value: T) -> bool {
+    self.start <= value && value < self.end
+  }
+
+  pub fn fully_contains(&self, other: &HalfOpenRange<T>) -> bool {
+    other.end <= self.end && other.start >= self.start
+  }
+
+  pub fn overlaps(&self, other: &HalfOpenRange<T>) -> bool {
+    self.intersection(other).is_some()
+  }
+
+  /// One starts where the other ends.
+  pub fn adjacent(&self, other: &HalfOpenRange<T>) -> bool {
+    other.end == self.start || self.end == other.start
+  }
+
+  pub fn intersection(&self, other: &HalfOpenRange<T>) -> Option<HalfOpenRange<T>> {
+    let overlap = HalfOpenRange::new(std::cmp::max(self.start, other.start), std::cmp::min(self.end, other.end));
+    (!overlap.is_empty()).then_some(overlap)
+  }
+
+  /// The values before `at`, and the values from `at` on. Either may be `None` if it would be empty.
+  pub fn split_at(&self, at: T) -> (Option<HalfOpenRange<T>>, Option<HalfOpenRange<T>>) {
+    let below = HalfOpenRange::new(self.start, std::cmp::min(self.end, at));
+    let above = HalfOpenRange::new(std::cmp::max(self.start, at), self.end);
+    ((!below.is_empty()).then_some(below), (!above.is_empty()).then_some(above))
+  }
+}
+
+impl<T> From<std::ops::RangeInclusive<T>> for ClosedRange<T> {
+  fn from(range: std::ops::RangeInclusive<T>) -> Self {
+    let (start, end) = range.into_inner();
+    ClosedRange { start, end }
+  }
+}
+
+impl<T> From<ClosedRange<T>> for std::ops::RangeInclusive<T> {
+  fn from(range: ClosedRange<T>) -> Self {
+    range.start..=range.end
+  }
+}
+
+impl<T> From<std::ops::Range<T>> for HalfOpenRange<T> {
+  fn from(range: std::ops::Range<T>) -> Self {
+    HalfOpenRange { start: range.start, end: range.end }
+  }
 }
 
-  pub fn size(&self) -> T {
-    self.end - self.start
+impl<T> From<HalfOpenRange<T>> for std::ops::Range<T> {
+  fn from(range: HalfOpenRange<T>) -> Self {
+    range.start..range.end
+  }
+}
+
+/// A [`ClosedRange`] that ends at its type's largest value has no [`HalfOpenRange`] equivalent, since
+/// there's no value after it to end on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndsAtMax;
+
+impl std::fmt::Display for EndsAtMax {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "A range ending at its type's largest value can't be half-open")
+  }
+}
+
+impl<T: Bound> TryFrom<ClosedRange<T>> for HalfOpenRange<T> {
+  type Error = EndsAtMax;
+
+  fn try_from(range: ClosedRange<T>) -> Result<Self, EndsAtMax> {
+    Ok(HalfOpenRange::new(range.start, range.end.checked_add(&One::one()).ok_or(EndsAtMax)?))
+  }
+}
+
+impl<T: Bound> From<HalfOpenRange<T>> for ClosedRange<T> {
+  /// An empty range comes out as `1..=0`, since `end - 1` might not exist (for `0..0` unsigned).
+  fn from(range: HalfOpenRange<T>) -> Self {
+    if range.is_empty() {
+      ClosedRange::new(T::one(), T::zero())
+    } else {
+      ClosedRange::new(range.start, range.end - One::one())
+    }
+  }
+}
+
+/// A set of values stored as the fewest disjoint, sorted, [`ClosedRange`]s, e.g. the x-values
+/// covered by a row of sensors. Overlapping and adjacent ranges are merged as they're inserted.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet<T> {
+  ranges: Vec<ClosedRange<T>>
+}
+
+impl<T> RangeSet<T>
+where
+  T: Bound
+{
+  pub fn new() -> RangeSet<T> {
+    RangeSet { ranges: vec![] }
+  }
+
+  /// The disjoint ranges, lowest first. No two are adjacent.
+  pub fn ranges(&self) -> &[ClosedRange<T>] {
+    &self.ranges
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+
+  pub fn insert(&mut self, range: ClosedRange<T>) {
+    if range.is_empty() {
+      return;
+    }
+    let mut merged = range;
+    let mut before = vec![];
+    let mut after = vec![];
+    for &existing in &self.ranges {
+      if existing.end < merged.start && !just_after(existing.end, merged.start) {
+        before.push(existing);
+      } else if merged.end < existing.start && !just_after(merged.end, existing.start) {
+        after.push(existing);
+      } else {
+        merged = ClosedRange::new(std::cmp::min(existing.start, merged.start), std::cmp::max(existing.end, merged.end));
+      }
+    }
+    before.push(merged);
+    before.extend(after);
+    self.ranges = before;
+  }
+
+  /// Subtract `range`, splitting any range it falls in the middle of.
+  pub fn remove(&mut self, range: ClosedRange<T>) {
+    if range.is_empty() {
+      return;
+    }
+    let mut remaining = vec![];
+    for &existing in &self.ranges {
+      if existing.end < range.start || range.end < existing.start {
+        remaining.push(existing);
+        continue;
+      }
+      if existing.start < range.start {
+        remaining.push(ClosedRange::new(existing.start, range.start - One::one()));
+      }
+      if range.end < existing.end {
+        remaining.push(ClosedRange::new(range.end + One::one(), existing.end));
+      }
+    }
+    self.ranges = remaining;
+  }
+
+  pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+    let mut result = self.clone();
+    for &range in &other.ranges {
+      result.insert(range);
+    }
+    result
+  }
+
+  pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+    // Both lists are sorted, so walk them together, always advancing whichever range ends first.
+    let mut ranges = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < self.ranges.len() && j < other.ranges.len() {
+      let (a, b) = (self.ranges[i], other.ranges[j]);
+      ranges.extend(a.intersection(&b));
+      if a.end < b.end { i += 1; } else { j += 1; }
+    }
+    RangeSet { ranges }
+  }
+
+  pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+    let mut result = self.clone();
+    for &range in &other.ranges {
+      result.remove(range);
+    }
+    result
+  }
+
+  /// Everything in `bounds` that isn't in the set.
+  pub fn complement(&self, bounds: ClosedRange<T>) -> RangeSet<T> {
+    RangeSet::from_iter([bounds]).difference(self)
+  }
+
+  /// The ranges between the set's ranges, i.e. the complement within the set's own extent.
+  pub fn gaps(&self) -> Vec<ClosedRange<T>> {
+    self.ranges.windows(2).map(|w| ClosedRange::new(w[0].end + One::one(), w[1].start - One::one())).collect()
+  }
+
+  /// How many values are in the set.
+  pub fn covered(&self) -> T {
+    self.ranges.iter().fold(T::zero(), |sum, range| sum + range.len())
+  }
+
+  pub fn contains(&self, value: T) -> bool {
+    self.range_containing(value).is_some()
+  }
+
+  /// The range in the set that `value` is in, if any.
+  pub fn range_containing(&self, value: T) -> Option<ClosedRange<T>> {
+    let i = self.ranges.partition_point(|range| range.end < value);
+    self.ranges.get(i).filter(|range| range.start <= value).copied()
+  }
+}
+
+impl<T> FromIterator<ClosedRange<T>> for RangeSet<T>
+where
+  T: Bound
+{
+  fn from_iter<I: IntoIterator<Item = ClosedRange<T>>>(iter: I) -> Self {
+    let mut set = RangeSet::new();
+    for range in iter {
+      set.insert(range);
+    }
+    set
+  }
+}
+
+/// Many [`ClosedRange`]s, e.g. every elf's section assignment, indexed so that finding the ones that
+/// overlap a range, fall inside it or contain it doesn't mean checking them all. Queries return the
+/// positions of the matching ranges in the list the tree was built from, in order. Empty ranges never
+/// match anything.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T> {
+  // The ranges and their positions, sorted by start. The tree is implicit: the root of each slice is
+  // its middle element, with the lower half as its left subtree and the upper half as its right.
+  ranges: Vec<(ClosedRange<T>, usize)>,
+  // The latest and earliest end in the subtree rooted at each element.
+  max_end: Vec<T>,
+  min_end: Vec<T>,
+  max_overlap: usize
+}
+
+impl<T: Bound> IntervalTree<T> {
+  pub fn new(ranges: &[ClosedRange<T>]) -> Self {
+    let mut sorted: Vec<(ClosedRange<T>, usize)> = ranges.iter().copied().zip(0..).filter(|(range, _)| !range.is_empty()).collect();
+    sorted.sort();
+    let ends = sorted.iter().map(|(range, _)| range.end).collect();
+    let mut tree = IntervalTree { ranges: sorted, max_end: ends, min_end: vec![], max_overlap: 0 };
+    tree.min_end = tree.max_end.clone();
+    tree.index(0, tree.ranges.len());
+    tree.max_overlap = tree.count_max_overlap();
+    tree
+  }
+
+  /// How many ranges the tree was built from, not counting empty ones.
+  pub fn len(&self) -> usize {
+    self.ranges.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+
+  /// The ranges that share at least one value with `range`.
+  pub fn overlapping(&self, range: ClosedRange<T>) -> Vec<usize> {
+    let mut found = vec![];
+    if !range.is_empty() {
+      self.find_overlapping(0, self.ranges.len(), range, &mut found);
+    }
+    found.sort();
+    found
+  }
+
+  /// The ranges that `range` fully contains.
+  pub fn contained_in(&self, range: ClosedRange<T>) -> Vec<usize> {
+    let mut found = vec![];
+    if !range.is_empty() {
+      self.find_contained_in(0, self.ranges.len(), range, &mut found);
+    }
+    found.sort();
+    found
+  }
+
+  /// The ranges that fully contain `range`.
+  pub fn containing(&self, range: ClosedRange<T>) -> Vec<usize> {
+    let mut found = vec![];
+    if !range.is_empty() {
+      self.find_containing(0, self.ranges.len(), range, &mut found);
+    }
+    found.sort();
+    found
+  }
+
+  /// The most ranges that any one value is in. Worked out once, when the tree is built.
+  pub fn max_overlap(&self) -> usize {
+    self.max_overlap
+  }
+
+  // Fill in `max_end` and `min_end` for the subtree of `lo..hi`, returning its root's.
+  fn index(&mut self, lo: usize, hi: usize) -> Option<(T, T)> {
+    if lo >= hi {
+      return None;
+    }
+    let mid = (lo + hi) / 2;
+    for (max, min) in [self.index(lo, mid), self.index(mid + 1, hi)].into_iter().flatten() {
+      self.max_end[mid] = std::cmp::max(self.max_end[mid], max);
+      self.min_end[mid] = std::cmp::min(self.min_end[mid], min);
+    }
+    Some((self.max_end[mid], self.min_end[mid]))
+  }
+
+  fn find_overlapping(&self, lo: usize, hi: usize, range: ClosedRange<T>, found: &mut Vec<usize>) {
+    let mid = (lo + hi) / 2;
+    if lo >= hi || self.max_end[mid] < range.start {
+      return; // Everything here ends too soon.
+    }
+    self.find_overlapping(lo, mid, range, found);
+    let (here, i) = self.ranges[mid];
+    if here.start <= range.end {
+      if here.overlaps(&range) {
+        found.push(i);
+      }
+      self.find_overlapping(mid + 1, hi, range, found);
+    }
+  }
+
+  fn find_contained_in(&self, lo: usize, hi: usize, range: ClosedRange<T>, found: &mut Vec<usize>) {
+    let mid = (lo + hi) / 2;
+    if lo >= hi || self.min_end[mid] > range.end {
+      return; // Everything here ends too late.
+    }
+    let (here, i) = self.ranges[mid];
+    if here.start >= range.start {
+      self.find_contained_in(lo, mid, range, found);
+    }
+    if here.start <= range.end {
+      if range.fully_contains(&here) {
+        found.push(i);
+      }
+      self.find_contained_in(mid + 1, hi, range, found);
+    }
+  }
+
+  fn find_containing(&self, lo: usize, hi: usize, range: ClosedRange<T>, found: &mut Vec<usize>) {
+    let mid = (lo + hi) / 2;
+    if lo >= hi || self.max_end[mid] < range.end {
+      return; // Everything here ends too soon.
+    }
+    self.find_containing(lo, mid, range, found);
+    let (here, i) = self.ranges[mid];
+    if here.start <= range.start {
+      if here.fully_contains(&range) {
+        found.push(i);
+      }
+      self.find_containing(mid + 1, hi, range, found);
+    }
+  }
+
+  // Sweep through the starts and ends in order, counting the ranges open at each start. A range that
+  // ends where another starts overlaps it, so starts go before ends at the same value.
+  fn count_max_overlap(&self) -> usize {
+    let mut ends: Vec<T> = self.ranges.iter().map(|(range, _)| range.end).collect();
+    ends.sort();
+    let (mut open, mut max, mut closed) = (0, 0, 0);
+    for (range, _) in &self.ranges {
+      while ends[closed] < range.start {
+        closed += 1;
+        open -= 1;
+      }
+      open += 1;
+      max = std::cmp::max(max, open);
+    }
+    max
   }
 }
 
@@ -56,43 +486,207 @@ mod tests {
 
   #[test]
   fn contains() {
-    assert!(Range::new(2,8).fully_contains(&Range::new(3, 7)));
-    assert!(Range::new(4,6).fully_contains(&Range::new(6, 6)));
-    assert!(!Range::new(4,5).fully_contains(&Range::new(6, 6)));
-    assert!(!Range::new(4,6).fully_contains(&Range::new(5, 7)));
+    assert!(ClosedRange::new(2,8).fully_contains(&ClosedRange::new(3, 7)));
+    assert!(ClosedRange::new(4,6).fully_contains(&ClosedRange::new(6, 6)));
+    assert!(!ClosedRange::new(4,5).fully_contains(&ClosedRange::new(6, 6)));
+    assert!(!ClosedRange::new(4,6).fully_contains(&ClosedRange::new(5, 7)));
   }
 
   #[test]
   fn overlaps() {
-    assert!(Range::new(3,5).overlaps(&Range::new(4,6)));
-    assert!(Range::new(4,6).overlaps(&Range::new(3,5)));
-    assert!(Range::new(2,6).overlaps(&Range::new(3,5)));
-    assert!(Range::new(3,5).overlaps(&Range::new(2,6)));
-    assert!(Range::new(3,5).overlaps(&Range::new(3,5)));
+    assert!(ClosedRange::new(3,5).overlaps(&ClosedRange::new(4,6)));
+    assert!(ClosedRange::new(4,6).overlaps(&ClosedRange::new(3,5)));
+    assert!(ClosedRange::new(2,6).overlaps(&ClosedRange::new(3,5)));
+    assert!(ClosedRange::new(3,5).overlaps(&ClosedRange::new(2,6)));
+    assert!(ClosedRange::new(3,5).overlaps(&ClosedRange::new(3,5)));
 
-    assert!(Range::new(5,7).overlaps(&Range::new(7,9)));
-    assert!(Range::new(2,8).overlaps(&Range::new(3,7)));
-    assert!(Range::new(6,6).overlaps(&Range::new(4,6)));
-    assert!(Range::new(2,6).overlaps(&Range::new(4,8)));
+    assert!(ClosedRange::new(5,7).overlaps(&ClosedRange::new(7,9)));
+    assert!(ClosedRange::new(2,8).overlaps(&ClosedRange::new(3,7)));
+    assert!(ClosedRange::new(6,6).overlaps(&ClosedRange::new(4,6)));
+    assert!(ClosedRange::new(2,6).overlaps(&ClosedRange::new(4,8)));
 
-    assert!(!Range::new(2,6).overlaps(&Range::new(7,8)));
-    assert!(!Range::new(3,5).overlaps(&Range::new(7,9)));
-    assert!(!Range::new(7,9).overlaps(&Range::new(3,5)));
-    assert!(!Range::new(2,4).overlaps(&Range::new(6,8)));
-    assert!(!Range::new(2,3).overlaps(&Range::new(4,5)));
+    assert!(!ClosedRange::new(2,6).overlaps(&ClosedRange::new(7,8)));
+    assert!(!ClosedRange::new(3,5).overlaps(&ClosedRange::new(7,9)));
+    assert!(!ClosedRange::new(7,9).overlaps(&ClosedRange::new(3,5)));
+    assert!(!ClosedRange::new(2,4).overlaps(&ClosedRange::new(6,8)));
+    assert!(!ClosedRange::new(2,3).overlaps(&ClosedRange::new(4,5)));
   }
 
   #[test]
   fn adjacent() {
-    assert!(Range::new(3, 5).adjacent(&Range::new(6,7)));
-    assert!(Range::new(6, 7).adjacent(&Range::new(3, 5)));
+    assert!(ClosedRange::new(3, 5).adjacent(&ClosedRange::new(6,7)));
+    assert!(ClosedRange::new(6, 7).adjacent(&ClosedRange::new(3, 5)));
+
+    assert!(!ClosedRange::new(6, 7).adjacent(&ClosedRange::new(3, 4)));
+    assert!(!ClosedRange::new(6, 8).adjacent(&ClosedRange::new(3, 7)));
+  }
+
+  #[test]
+  fn len() {
+    assert_eq!(ClosedRange::new(-2, 24).len(), 27);
+    assert_eq!(ClosedRange::new(5, 5).len(), 1);
+    assert_eq!(ClosedRange::new(5, 4).len(), 0);
+    assert!(ClosedRange::new(5u32, 4).is_empty());
+
+    assert_eq!(HalfOpenRange::new(-2, 24).len(), 26);
+    assert_eq!(HalfOpenRange::new(5, 5).len(), 0);
+    assert_eq!(HalfOpenRange::new(5u32, 4).len(), 0);
+  }
+
+  #[test]
+  fn contains_values() {
+    assert!(ClosedRange::new(2, 4).contains(4));
+    assert!(!HalfOpenRange::new(2, 4).contains(4));
+    assert!(HalfOpenRange::new(2, 4).contains(2));
+    assert!(!ClosedRange::new(2, 4).contains(1));
+  }
+
+  #[test]
+  fn half_open_overlaps() {
+    assert!(HalfOpenRange::new(3, 5).overlaps(&HalfOpenRange::new(4, 6)));
+    assert!(!HalfOpenRange::new(5, 7).overlaps(&HalfOpenRange::new(7, 9)));
+    assert!(HalfOpenRange::new(5, 7).adjacent(&HalfOpenRange::new(7, 9)));
+    assert!(HalfOpenRange::new(2, 8).fully_contains(&HalfOpenRange::new(3, 8)));
+    assert!(!HalfOpenRange::new(2, 8).overlaps(&HalfOpenRange::new(4, 4)));
+  }
+
+  #[test]
+  fn intersection() {
+    assert_eq!(ClosedRange::new(1, 5).intersection(&ClosedRange::new(5, 9)), Some(ClosedRange::new(5, 5)));
+    assert_eq!(ClosedRange::new(1, 4).intersection(&ClosedRange::new(5, 9)), None);
+    assert_eq!(HalfOpenRange::new(1, 5).intersection(&HalfOpenRange::new(5, 9)), None);
+    assert_eq!(HalfOpenRange::new(1, 6).intersection(&HalfOpenRange::new(5, 9)), Some(HalfOpenRange::new(5, 6)));
+  }
+
+  #[test]
+  fn split_at() {
+    assert_eq!(ClosedRange::new(1, 5).split_at(3), (Some(ClosedRange::new(1, 2)), Some(ClosedRange::new(3, 5))));
+    assert_eq!(ClosedRange::new(1, 5).split_at(1), (None, Some(ClosedRange::new(1, 5))));
+    assert_eq!(ClosedRange::new(1, 5).split_at(9), (Some(ClosedRange::new(1, 5)), None));
+    assert_eq!(HalfOpenRange::new(1, 5).split_at(3), (Some(HalfOpenRange::new(1, 3)), Some(HalfOpenRange::new(3, 5))));
+    assert_eq!(HalfOpenRange::new(1, 5).split_at(5), (Some(HalfOpenRange::new(1, 5)), None));
+  }
+
+  #[test]
+  fn unsigned_edges() {
+    assert_eq!(ClosedRange::<u32>::new(0, 5).split_at(0), (None, Some(ClosedRange::new(0, 5))));
+    assert_eq!(ClosedRange::<u32>::new(0, 5).split_at(1), (Some(ClosedRange::new(0, 0)), Some(ClosedRange::new(1, 5))));
+    assert_eq!(ClosedRange::new(3, u32::MAX).split_at(u32::MAX), (Some(ClosedRange::new(3, u32::MAX - 1)), Some(ClosedRange::new(u32::MAX, u32::MAX))));
+    assert_eq!(HalfOpenRange::try_from(ClosedRange::new(3, u32::MAX)), Err(EndsAtMax));
+    assert_eq!(HalfOpenRange::try_from(ClosedRange::new(3, u32::MAX - 1)), Ok(HalfOpenRange::new(3, u32::MAX)));
+    assert_eq!(ClosedRange::new(0, u32::MAX).checked_len(), None);
+    assert_eq!(ClosedRange::new(1, u32::MAX).checked_len(), Some(u32::MAX));
+    assert_eq!(ClosedRange::new(i8::MIN, i8::MAX).checked_len(), None);
+    assert_eq!(ClosedRange::new(0, u8::MAX - 1).len(), u8::MAX);
+    assert_eq!(ClosedRange::new(3, u8::MAX).split_at(0), (None, Some(ClosedRange::new(3, u8::MAX))));
+
+    assert!(ClosedRange::from(HalfOpenRange::<u32>::new(0, 0)).is_empty());
+    assert_eq!(ClosedRange::from(HalfOpenRange::<u32>::new(0, 1)), ClosedRange::new(0, 0));
+
+    assert!(ClosedRange::new(0u8, 9).adjacent(&ClosedRange::new(10, u8::MAX)));
+    assert!(!ClosedRange::new(0u8, u8::MAX).adjacent(&ClosedRange::new(0, 0)));
+    assert!(!ClosedRange::new(u8::MAX, u8::MAX).adjacent(&ClosedRange::new(0, 0)));
 
-    assert!(!Range::new(6, 7).adjacent(&Range::new(3, 4)));
-    assert!(!Range::new(6, 8).adjacent(&Range::new(3, 7)));
+    let mut set = RangeSet::from_iter([ClosedRange::new(u8::MAX, u8::MAX), ClosedRange::new(0, 0)]);
+    set.insert(ClosedRange::new(250, 254));
+    set.insert(ClosedRange::new(1, 2));
+    assert_eq!(set.ranges(), &[ClosedRange::new(0, 2), ClosedRange::new(250, u8::MAX)]);
+    set.remove(ClosedRange::new(0, 0));
+    set.remove(ClosedRange::new(u8::MAX, u8::MAX));
+    assert_eq!(set.ranges(), &[ClosedRange::new(1, 2), ClosedRange::new(250, 254)]);
+    assert_eq!(set.complement(ClosedRange::new(0, u8::MAX)).ranges(), &[ClosedRange::new(0, 0), ClosedRange::new(3, 249), ClosedRange::new(u8::MAX, u8::MAX)]);
   }
 
   #[test]
-  fn size() {
-    assert_eq!(Range::new(-2, 24).size(), 26);
+  fn conversions() {
+    assert_eq!(ClosedRange::from(2..=4), ClosedRange::new(2, 4));
+    assert_eq!(HalfOpenRange::from(2..4), HalfOpenRange::new(2, 4));
+    assert_eq!(std::ops::RangeInclusive::from(ClosedRange::new(2, 4)).count(), 3);
+    assert_eq!(std::ops::Range::from(HalfOpenRange::new(2, 4)).count(), 2);
+    assert_eq!(HalfOpenRange::try_from(ClosedRange::new(2, 4)), Ok(HalfOpenRange::new(2, 5)));
+    assert_eq!(ClosedRange::from(HalfOpenRange::new(2, 5)), ClosedRange::new(2, 4));
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn range_set_merges() {
+    let mut set = RangeSet::from_iter([ClosedRange::new(10, 12), ClosedRange::new(1, 3), ClosedRange::new(2, 5)]);
+    assert_eq!(set.ranges(), &[ClosedRange::new(1, 5), ClosedRange::new(10, 12)]);
+
+    set.insert(ClosedRange::new(6, 7));
+    assert_eq!(set.ranges(), &[ClosedRange::new(1, 7), ClosedRange::new(10, 12)]);
+    assert_eq!(set.gaps(), vec![ClosedRange::new(8, 9)]);
+    assert_eq!(set.covered(), 10);
+
+    set.insert(ClosedRange::new(0, 20));
+    assert_eq!(set.ranges(), &[ClosedRange::new(0, 20)]);
+  }
+
+  #[test]
+  fn range_set_removes() {
+    let mut set = RangeSet::from_iter([ClosedRange::new(-5, 5), ClosedRange::new(8, 9)]);
+    set.remove(ClosedRange::new(0, 0));
+    set.remove(ClosedRange::new(4, 8));
+    assert_eq!(set.ranges(), &[ClosedRange::new(-5, -1), ClosedRange::new(1, 3), ClosedRange::new(9, 9)]);
+    assert_eq!((set.contains(-1), set.contains(0), set.contains(9), set.contains(10)), (true, false, true, false));
+    assert_eq!(set.range_containing(2), Some(ClosedRange::new(1, 3)));
+
+    set.remove(ClosedRange::new(-10, 10));
+    assert!(set.is_empty());
+  }
+
+  #[test]
+  fn range_set_ignores_empty_ranges() {
+    let mut set = RangeSet::new();
+    set.insert(ClosedRange::new(5, 4));
+    assert!(set.is_empty());
+    set.insert(ClosedRange::new(20, 30));
+    assert_eq!(set.gaps(), vec![]);
+
+    let mut set = RangeSet::from_iter([ClosedRange::new(0, 10)]);
+    set.remove(ClosedRange::new(5, 4));
+    assert_eq!(set.ranges(), &[ClosedRange::new(0, 10)]);
+
+    assert!(set.complement(ClosedRange::new(1, 0)).is_empty());
+  }
+
+  #[test]
+  fn range_set_operations() {
+    let a = RangeSet::from_iter([ClosedRange::new(0, 4), ClosedRange::new(10, 14)]);
+    let b = RangeSet::from_iter([ClosedRange::new(3, 11), ClosedRange::new(20, 21)]);
+    assert_eq!(a.union(&b).ranges(), &[ClosedRange::new(0, 14), ClosedRange::new(20, 21)]);
+    assert_eq!(a.intersection(&b).ranges(), &[ClosedRange::new(3, 4), ClosedRange::new(10, 11)]);
+    assert_eq!(a.difference(&b).ranges(), &[ClosedRange::new(0, 2), ClosedRange::new(12, 14)]);
+    assert_eq!(a.complement(ClosedRange::new(-2, 12)).ranges(), &[ClosedRange::new(-2, -1), ClosedRange::new(5, 9)]);
+  }
+
+  #[test]
+  fn interval_tree_queries() {
+    let ranges = [ClosedRange::new(2, 4), ClosedRange::new(6, 8), ClosedRange::new(2, 3), ClosedRange::new(4, 5), ClosedRange::new(6, 6), ClosedRange::new(2, 6), ClosedRange::new(9, 1)];
+    let tree = IntervalTree::new(&ranges);
+    assert_eq!(tree.len(), 6);
+    assert_eq!(tree.overlapping(ClosedRange::new(5, 6)), vec![1, 3, 4, 5]);
+    assert_eq!(tree.overlapping(ClosedRange::new(9, 20)), vec![]);
+    assert_eq!(tree.contained_in(ClosedRange::new(2, 5)), vec![0, 2, 3]);
+    assert_eq!(tree.containing(ClosedRange::new(4, 4)), vec![0, 3, 5]);
+    assert_eq!(tree.containing(ClosedRange::new(3, 1)), vec![]);
+    assert_eq!(tree.max_overlap(), 3);
+    assert_eq!(IntervalTree::<u32>::new(&[]).max_overlap(), 0);
+  }
+
+  #[test]
+  fn interval_tree_matches_checking_every_range() {
+    use proptest::prelude::*;
+    let range = || (0..50i32, 0..12i32).prop_map(|(start, len)| ClosedRange::new(start, start + len - 1));
+    proptest!(|(ranges in prop::collection::vec(range(), 0..60), query in range())| {
+      let tree = IntervalTree::new(&ranges);
+      let matching = |matches: &dyn Fn(&ClosedRange<i32>) -> bool| -> Vec<usize> {
+        (0..ranges.len()).filter(|&i| !ranges[i].is_empty() && !query.is_empty() && matches(&ranges[i])).collect()
+      };
+      prop_assert_eq!(tree.overlapping(query), matching(&|r| r.overlaps(&query)));
+      prop_assert_eq!(tree.contained_in(query), matching(&|r| query.fully_contains(r)));
+      prop_assert_eq!(tree.containing(query), matching(&|r| r.fully_contains(&query)));
+      let deepest = (0..60).map(|v| ranges.iter().filter(|r| r.contains(v)).count()).max().unwrap();
+      prop_assert_eq!(tree.max_overlap(), deepest);
+    });
+  }
+}
@@ -0,0 +1,339 @@
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+use crate::error::InputError;
+use crate::grid2::Grid2;
+use crate::input::Input;
+use crate::solution::Part;
+
+/// A color as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// One cell of a frame: the character drawn for it in text, and its color in the terminal and in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+  pub glyph: char,
+  pub color: Rgb
+}
+
+impl Cell {
+  pub const fn new(glyph: char, color: Rgb) -> Self {
+    Cell { glyph, color }
+  }
+}
+
+/// A picture of a simulation at one moment: a rectangle of cells, row 0 at the top.
+///
+/// Implement it on the simulation's state (or build a `Grid2<Cell>`, which is a frame already), then
+/// draw it as plain text, ANSI-colored text or an image, or hand it to a [`Recorder`] each step.
+pub trait Frame {
+  fn rows(&self) -> usize;
+  fn cols(&self) -> usize;
+  fn cell(&self, row: usize, col: usize) -> Cell;
+
+  /// The glyphs, one line per row.
+  fn text(&self) -> String {
+    (0..self.rows())
+      .map(|r| (0..self.cols()).map(|c| self.cell(r, c).glyph).collect::<String>())
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  /// The glyphs in their colors, for a terminal that supports 24-bit color.
+  fn ansi(&self) -> String {
+    let mut ansi = String::new();
+    for r in 0..self.rows() {
+      let mut color = None;
+      for c in 0..self.cols() {
+        let cell = self.cell(r, c);
+        if color != Some(cell.color) {
+          let [red, green, blue] = cell.color;
+          write!(ansi, "\x1b[38;2;{red};{green};{blue}m").unwrap();
+          color = Some(cell.color);
+        }
+        ansi.push(cell.glyph);
+      }
+      ansi.push_str("\x1b[0m\n");
+    }
+    ansi
+  }
+
+  /// The colors as a binary PPM image, with each cell `scale` pixels square.
+  fn ppm(&self, scale: usize) -> Vec<u8> {
+    let mut ppm = format!["P6\n{} {}\n255\n", self.cols() * scale, self.rows() * scale].into_bytes();
+    ppm.extend(self.pixels(scale));
+    ppm
+  }
+
+  /// The colors as RGB pixels, row by row, with each cell `scale` pixels square.
+  fn pixels(&self, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(self.rows() * self.cols() * scale * scale * 3);
+    for r in 0..self.rows() {
+      let row: Vec<u8> = (0..self.cols()).flat_map(|c| self.cell(r, c).color.repeat(scale)).collect();
+      for _ in 0..scale {
+        pixels.extend(&row);
+      }
+    }
+    pixels
+  }
+
+  /// A copy of the frame as it is now.
+  fn snapshot(&self) -> Grid2<Cell> {
+    Grid2::from_rows((0..self.rows()).map(|r| (0..self.cols()).map(|c| self.cell(r, c)).collect()).collect())
+  }
+}
+
+impl Frame for Grid2<Cell> {
+  fn rows(&self) -> usize {
+    Grid2::rows(self)
+  }
+
+  fn cols(&self) -> usize {
+    Grid2::cols(self)
+  }
+
+  fn cell(&self, row: usize, col: usize) -> Cell {
+    self[(row, col)]
+  }
+}
+
+/// Collects snapshots of a running simulation, to play back in the terminal or save as images.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+  frames: Vec<Grid2<Cell>>,
+  every: usize,
+  offered: usize
+}
+
+impl Default for Recorder {
+  fn default() -> Self {
+    Recorder::new()
+  }
+}
+
+impl Recorder {
+  pub fn new() -> Self {
+    Recorder { frames: vec![], every: 1, offered: 0 }
+  }
+
+  /// Keep only every `n`th frame, for simulations with too many steps to watch them all.
+  pub fn every(mut self, n: usize) -> Self {
+    self.every = n.max(1);
+    self
+  }
+
+  /// Offer the simulation's current state; it's kept if it's one of every `n`th.
+  pub fn record(&mut self, frame: &impl Frame) {
+    if self.offered.is_multiple_of(self.every) {
+      self.frames.push(frame.snapshot());
+    }
+    self.offered += 1;
+  }
+
+  /// Keep the current state regardless of `every`, e.g. for the final state.
+  pub fn record_always(&mut self, frame: &impl Frame) {
+    self.frames.push(frame.snapshot());
+  }
+
+  pub fn frames(&self) -> &[Grid2<Cell>] {
+    &self.frames
+  }
+
+  /// Draw each frame over the last in the terminal, waiting `delay` between them.
+  pub fn play(&self, delay: Duration) {
+    for frame in &self.frames {
+      print!("\x1b[2J\x1b[H{}", frame.ansi());
+      std::thread::sleep(delay);
+    }
+  }
+
+  /// Save each frame in `dir` as `frame00000.ppm`, `frame00001.ppm` and so on (or `.png`, or `.txt`).
+  pub fn save(&self, dir: &Path, format: ImageFormat, scale: usize) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!["Couldn't create \"{}\": {e}", dir.display()])?;
+    for (i, frame) in self.frames.iter().enumerate() {
+      let path = dir.join(format!["frame{i:05}.{}", format.extension()]);
+      let bytes = match format {
+        ImageFormat::Text => frame.text().into_bytes(),
+        ImageFormat::Ppm => frame.ppm(scale),
+        #[cfg(feature = "png")]
+        ImageFormat::Png => png(frame, scale)?
+      };
+      std::fs::write(&path, bytes).map_err(|e| format!["Couldn't write \"{}\": {e}", path.display()])?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+  Text,
+  Ppm,
+  /// Needs the `png` feature.
+  #[cfg(feature = "png")]
+  Png
+}
+
+impl ImageFormat {
+  fn extension(&self) -> &'static str {
+    match self {
+      ImageFormat::Text => "txt",
+      ImageFormat::Ppm => "ppm",
+      #[cfg(feature = "png")]
+      ImageFormat::Png => "png"
+    }
+  }
+}
+
+#[cfg(feature = "png")]
+fn png(frame: &Grid2<Cell>, scale: usize) -> Result<Vec<u8>, String> {
+  let mut bytes = vec![];
+  let mut encoder = png::Encoder::new(&mut bytes, (frame.cols() * scale) as u32, (frame.rows() * scale) as u32);
+  encoder.set_color(png::ColorType::Rgb);
+  encoder.set_depth(png::BitDepth::Eight);
+  encoder.write_header()
+    .and_then(|mut writer| writer.write_image_data(&frame.pixels(scale)))
+    .map_err(|e| e.to_string())?;
+  Ok(bytes)
+}
+
+const ANIMATE_USAGE: &str = "Usage: cargo run --release --example animate -- <input> [--part 1|2] [--every n] [--delay ms] [--save dir [--format txt|ppm|png] [--scale n]]";
+
+/// The `main` for a day's `animate` example: load the input file given as the first argument, run
+/// `simulate` on it for a part (1 unless `--part 2` is given) with a [`Recorder`], then play the frames
+/// in the terminal, or save them with `--save`.
+pub fn animate_from_args(simulate: impl FnOnce(&Input, Part, &mut Recorder) -> Result<(), InputError>) {
+  let options = AnimateOptions::parse(crate::args_iter()).unwrap_or_else(|err| {
+    eprintln!("{err}\n{ANIMATE_USAGE}");
+    std::process::exit(2);
+  });
+
+  let mut recorder = Recorder::new().every(options.every);
+  let result = Input::load(&options.input).and_then(|input| simulate(&input, options.part, &mut recorder));
+  let result = result.map_err(|err| err.to_string()).and_then(|_| match &options.save {
+    Some(dir) => recorder.save(Path::new(dir), options.format, options.scale),
+    None => {
+      recorder.play(options.delay);
+      Ok(())
+    }
+  });
+
+  if let Err(err) = result {
+    eprintln!("{err}");
+    std::process::exit(1);
+  }
+}
+
+#[derive(Debug, PartialEq)]
+struct AnimateOptions {
+  input: String,
+  part: Part,
+  every: usize,
+  delay: Duration,
+  save: Option<String>,
+  format: ImageFormat,
+  scale: usize
+}
+
+impl AnimateOptions {
+  fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+    let mut options = AnimateOptions {
+      input: args.next().ok_or("Missing input")?,
+      part: Part::One,
+      every: 1,
+      delay: Duration::from_millis(50),
+      save: None,
+      format: ImageFormat::Ppm,
+      scale: 4
+    };
+    while let Some(arg) = args.next() {
+      let value = args.next().ok_or(format!["Missing value after {arg}"])?;
+      let number = || value.parse::<usize>().map_err(|_| format!["Invalid {arg} \"{value}\""]);
+      match arg.as_str() {
+        "--part" => options.part = number().ok().and_then(|n| Part::from_number(n as u8)).ok_or(format!["Invalid part \"{value}\""])?,
+        "--every" => options.every = number()?,
+        "--delay" => options.delay = Duration::from_millis(number()? as u64),
+        "--scale" => options.scale = number()?,
+        "--save" => options.save = Some(value),
+        "--format" => options.format = match value.as_str() {
+          "txt" => ImageFormat::Text,
+          "ppm" => ImageFormat::Ppm,
+          #[cfg(feature = "png")]
+          "png" => ImageFormat::Png,
+          _ => return Err(format!["Unsupported format \"{value}\""])
+        },
+        _ => return Err(format!["Unexpected argument \"{arg}\""])
+      }
+    }
+    Ok(options)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ROCK: Cell = Cell::new('#', [128, 128, 128]);
+  const SAND: Cell = Cell::new('o', [255, 200, 0]);
+  const AIR: Cell = Cell::new('.', [0, 0, 0]);
+
+  fn frame() -> Grid2<Cell> {
+    Grid2::from_rows(vec![vec![AIR, SAND], vec![ROCK, ROCK]])
+  }
+
+  #[test]
+  fn draws_text() {
+    assert_eq!(frame().text(), ".o\n##");
+    assert_eq!(
+      frame().ansi(),
+      "\x1b[38;2;0;0;0m.\x1b[38;2;255;200;0mo\x1b[0m\n\x1b[38;2;128;128;128m##\x1b[0m\n"
+    );
+  }
+
+  #[test]
+  fn draws_images() {
+    let ppm = frame().ppm(2);
+    assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+    let pixels = &ppm[b"P6\n4 4\n255\n".len()..];
+    assert_eq!(pixels.len(), 4 * 4 * 3);
+    // The first row of pixels is two black pixels and two sand-colored ones.
+    assert_eq!(&pixels[..12], &[0, 0, 0, 0, 0, 0, 255, 200, 0, 255, 200, 0]);
+    assert_eq!(&pixels[12..24], &pixels[..12]);
+  }
+
+  #[test]
+  fn records_every_nth_frame() {
+    let mut recorder = Recorder::new().every(2);
+    let mut grid = frame();
+    for col in 0..2 {
+      grid[(0, col)] = SAND;
+      recorder.record(&grid);
+    }
+    recorder.record(&grid);
+    recorder.record_always(&grid);
+    let frames: Vec<String> = recorder.frames().iter().map(|frame| frame.text()).collect();
+    assert_eq!(frames, ["oo\n##", "oo\n##", "oo\n##"]);
+  }
+
+  #[test]
+  fn saves_frames() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut recorder = Recorder::new();
+    recorder.record(&frame());
+    recorder.record(&frame());
+    recorder.save(dir.path(), ImageFormat::Text, 1).unwrap();
+    assert_eq!(std::fs::read_to_string(dir.path().join("frame00001.txt")).unwrap(), ".o\n##");
+  }
+
+  #[test]
+  fn parses_animate_options() {
+    let parse = |s: &str| AnimateOptions::parse(s.split_whitespace().map(String::from));
+    let options = parse("sample.txt --every 10 --save frames --format txt").unwrap();
+    assert_eq!((options.part, options.every, options.save.as_deref(), options.format), (Part::One, 10, Some("frames"), ImageFormat::Text));
+    assert_eq!(parse("sample.txt --part 2").map(|options| options.part), Ok(Part::Two));
+    assert!(parse("sample.txt --part 3").is_err());
+    assert!(parse("").is_err());
+    assert!(parse("sample.txt --every").is_err());
+    assert!(parse("sample.txt --scale big").is_err());
+    assert!(parse("sample.txt --format gif").is_err());
+  }
+}
@@ -14,22 +14,176 @@ pub enum Outcome {
 
 impl Play {
   pub fn outcome(&self, other: Play) -> Outcome {
-    use Play::*;
-    use Outcome::*;
-    match (*self, other) {
-      (Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => Win,
-      (Rock, Rock) | (Paper, Paper) | (Scissors, Scissors) => Draw,
-      (Scissors, Rock) | (Rock, Paper) | (Paper, Scissors) => Lose
-    }
+    CyclicGame::ROCK_PAPER_SCISSORS.outcome(self.number(), other.number())
   }
 
   pub fn play_for_outcome(&self, desired_outcome_against_self: Outcome) -> Play {
+    Play::from_number(CyclicGame::ROCK_PAPER_SCISSORS.play_for_outcome(self.number(), desired_outcome_against_self))
+  }
+
+  /// The play's number in `CyclicGame::ROCK_PAPER_SCISSORS`.
+  pub fn number(&self) -> Move {
+    *self as Move
+  }
+
+  pub fn from_number(number: Move) -> Play {
+    [Play::Rock, Play::Paper, Play::Scissors][number % 3]
+  }
+}
+
+/// A move in a [`CyclicGame`], numbered from 0.
+pub type Move = usize;
+
+/// A game like rock paper scissors, where the moves go around a circle and each one beats the half of
+/// the others just before it and loses to the half just after. So the outcome only depends on how
+/// far apart two moves are, and there has to be an odd number of them (at least 3).
+///
+/// Rock paper scissors is 0 = rock, 1 = paper, 2 = scissors. Rock paper scissors lizard Spock goes
+/// 0 = rock, 1 = Spock, 2 = paper, 3 = lizard, 4 = scissors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CyclicGame {
+  moves: usize
+}
+
+impl CyclicGame {
+  pub const ROCK_PAPER_SCISSORS: CyclicGame = CyclicGame { moves: 3 };
+  pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: CyclicGame = CyclicGame { moves: 5 };
+
+  pub fn new(moves: usize) -> Self {
+    assert!(moves % 2 == 1 && moves >= 3, "A cyclic game needs an odd number of moves, at least 3, not {moves}");
+    CyclicGame { moves }
+  }
+
+  pub fn moves(&self) -> usize {
+    self.moves
+  }
+
+  /// How playing `mine` against `theirs` turns out for me.
+  pub fn outcome(&self, mine: Move, theirs: Move) -> Outcome {
+    match (mine + self.moves - theirs % self.moves) % self.moves {
+      0 => Outcome::Draw,
+      ahead if ahead <= self.moves / 2 => Outcome::Win,
+      _ => Outcome::Lose
+    }
+  }
+
+  /// A move to play against `theirs` that turns out as `outcome` for me: the one just after it to
+  /// win or just before it to lose.
+  pub fn play_for_outcome(&self, theirs: Move, outcome: Outcome) -> Move {
+    let ahead = match outcome {
+      Outcome::Draw => 0,
+      Outcome::Win => 1,
+      Outcome::Lose => self.moves - 1
+    };
+    (theirs + ahead) % self.moves
+  }
+}
+
+/// Points for a round: some for the move I played, plus some for how it turned out.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Scoring {
+  /// By move number.
+  pub moves: Vec<i32>,
+  pub win: i32,
+  pub draw: i32,
+  pub lose: i32
+}
+
+impl Scoring {
+  /// The puzzle's scoring for a game of `moves` moves: 1 for move 0, 2 for move 1 and so on, plus 6
+  /// for a win, 3 for a draw and 0 for a loss.
+  pub fn puzzle(moves: usize) -> Self {
+    Scoring { moves: (1..=moves as i32).collect(), win: 6, draw: 3, lose: 0 }
+  }
+
+  pub fn score(&self, game: &CyclicGame, mine: Move, theirs: Move) -> i32 {
+    let outcome = match game.outcome(mine, theirs) {
+      Outcome::Win => self.win,
+      Outcome::Draw => self.draw,
+      Outcome::Lose => self.lose
+    };
+    self.moves[mine] + outcome
+  }
+}
+
+/// What the symbols in a strategy guide mean: which move each one is in the opponent's column and in
+/// mine, and which outcome each one is when my column is read as outcomes instead.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Symbols {
+  /// The symbol for each move, by move number.
+  pub theirs: Vec<String>,
+  pub mine: Vec<String>,
+  /// The symbols for losing, drawing and winning.
+  pub outcomes: [String; 3]
+}
+
+impl Symbols {
+  /// The puzzle's: A, B and C for the opponent and X, Y and Z for me, as moves or as lose, draw, win.
+  pub fn puzzle() -> Self {
+    let strings = |symbols: [&str; 3]| symbols.map(String::from);
+    Symbols { theirs: strings(["A", "B", "C"]).into(), mine: strings(["X", "Y", "Z"]).into(), outcomes: strings(["X", "Y", "Z"]) }
+  }
+
+  pub fn their_move(&self, symbol: &str) -> Option<Move> {
+    self.theirs.iter().position(|s| s == symbol)
+  }
+
+  pub fn my_move(&self, symbol: &str) -> Option<Move> {
+    self.mine.iter().position(|s| s == symbol)
+  }
+
+  pub fn outcome(&self, symbol: &str) -> Option<Outcome> {
+    self.outcomes.iter().position(|s| s == symbol).map(|i| [Outcome::Lose, Outcome::Draw, Outcome::Win][i])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const OUTCOMES: [Outcome; 3] = [Outcome::Win, Outcome::Lose, Outcome::Draw];
+
+  #[test]
+  fn rock_paper_scissors() {
     use Play::*;
     use Outcome::*;
-    match (*self, desired_outcome_against_self) {
-      (Rock, Draw) | (Paper, Lose) | (Scissors, Win) => Rock,
-      (Rock, Win) | (Paper, Draw) | (Scissors, Lose) => Paper,
-      (Rock, Lose) | (Paper, Win) | (Scissors, Draw) => Scissors
+    assert_eq!([Rock.outcome(Scissors), Paper.outcome(Rock), Scissors.outcome(Paper)], [Win; 3]);
+    assert_eq!([Rock.outcome(Rock), Paper.outcome(Paper), Scissors.outcome(Scissors)], [Draw; 3]);
+    assert_eq!([Scissors.outcome(Rock), Rock.outcome(Paper), Paper.outcome(Scissors)], [Lose; 3]);
+    assert_eq!([Rock.play_for_outcome(Win), Rock.play_for_outcome(Lose), Rock.play_for_outcome(Draw)], [Paper, Scissors, Rock]);
+    assert_eq!([Scissors.play_for_outcome(Win), Paper.play_for_outcome(Lose)], [Rock, Rock]);
+  }
+
+  #[test]
+  fn lizard_spock() {
+    let game = CyclicGame::ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+    let [rock, spock, paper, lizard, scissors] = [0, 1, 2, 3, 4];
+    for (winner, loser) in [(scissors, paper), (paper, rock), (rock, lizard), (lizard, spock), (spock, scissors),
+                            (scissors, lizard), (lizard, paper), (paper, spock), (spock, rock), (rock, scissors)] {
+      assert_eq!((game.outcome(winner, loser), game.outcome(loser, winner)), (Outcome::Win, Outcome::Lose));
     }
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn every_move_wins_and_loses_equally() {
+    for moves in [3, 5, 7, 101] {
+      let game = CyclicGame::new(moves);
+      for theirs in 0..moves {
+        let wins = (0..moves).filter(|&mine| game.outcome(mine, theirs) == Outcome::Win).count();
+        let losses = (0..moves).filter(|&mine| game.outcome(mine, theirs) == Outcome::Lose).count();
+        assert_eq!((wins, losses), (moves / 2, moves / 2));
+        for outcome in OUTCOMES {
+          assert_eq!(game.outcome(game.play_for_outcome(theirs, outcome), theirs), outcome);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn puzzle_scoring() {
+    let (game, scoring) = (CyclicGame::ROCK_PAPER_SCISSORS, Scoring::puzzle(3));
+    assert_eq!(scoring.score(&game, 1, 0), 8);
+    assert_eq!(scoring.score(&game, 0, 1), 1);
+    assert_eq!(scoring.score(&game, 2, 2), 6);
+  }
+}
@@ -0,0 +1,301 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Something to search: which nodes can be reached from a node, and what each step costs.
+///
+/// Implement it on a small struct holding whatever the puzzle needs to decide the neighbors (a height map,
+/// a set of walls), or use the implementation for an adjacency list (`HashMap<Node, Vec<Node>>`).
+pub trait Graph {
+  type Node: Clone + Eq + Hash;
+
+  fn neighbors(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+  /// The cost of stepping from `from` to its neighbor `to`. [`bfs`] ignores this and counts steps.
+  fn cost(&self, _from: &Self::Node, _to: &Self::Node) -> u64 {
+    1
+  }
+}
+
+impl<N: Clone + Eq + Hash> Graph for HashMap<N, Vec<N>> {
+  type Node = N;
+
+  fn neighbors(&self, node: &N) -> Vec<N> {
+    self.get(node).cloned().unwrap_or_default()
+  }
+}
+
+/// The result of a search: the shortest distance to every node it reached, and the path there.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+  distances: HashMap<N, u64>,
+  parents: HashMap<N, N>,
+  goal: Option<N>
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+  fn new() -> Self {
+    Search { distances: HashMap::new(), parents: HashMap::new(), goal: None }
+  }
+
+  /// The shortest distance from (the nearest) start to `node`, if the search reached it.
+  pub fn distance(&self, node: &N) -> Option<u64> {
+    self.distances.get(node).copied()
+  }
+
+  /// Every node reached, with its distance.
+  pub fn distances(&self) -> &HashMap<N, u64> {
+    &self.distances
+  }
+
+  /// The shortest path from a start to `node`, including both ends.
+  pub fn path(&self, node: &N) -> Option<Vec<N>> {
+    self.distances.get(node)?;
+    let mut path = vec![node.clone()];
+    while let Some(parent) = self.parents.get(path.last().unwrap()) {
+      path.push(parent.clone());
+    }
+    path.reverse();
+    Some(path)
+  }
+
+  /// The goal an [`astar`] search stopped at, if it found one.
+  pub fn goal(&self) -> Option<&N> {
+    self.goal.as_ref()
+  }
+}
+
+/// Breadth-first search from `start` to everything reachable, counting steps.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> Search<G::Node> {
+  bfs_multi(graph, [start])
+}
+
+/// Breadth-first search from whichever of `starts` is nearest to each node.
+pub fn bfs_multi<G: Graph>(graph: &G, starts: impl IntoIterator<Item = G::Node>) -> Search<G::Node> {
+  let mut search = Search::new();
+  let mut queue = VecDeque::new();
+  for start in starts {
+    if search.distances.insert(start.clone(), 0).is_none() {
+      queue.push_back(start);
+    }
+  }
+
+  while let Some(curr) = queue.pop_front() {
+    let next_distance = search.distances[&curr] + 1;
+    for next in graph.neighbors(&curr) {
+      if !search.distances.contains_key(&next) {
+        search.distances.insert(next.clone(), next_distance);
+        search.parents.insert(next.clone(), curr.clone());
+        queue.push_back(next);
+      }
+    }
+  }
+  search
+}
+
+/// Dijkstra's algorithm from `start` to everything reachable, using [`Graph::cost`].
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node) -> Search<G::Node> {
+  dijkstra_multi(graph, [start])
+}
+
+/// Dijkstra's algorithm from whichever of `starts` is nearest to each node.
+pub fn dijkstra_multi<G: Graph>(graph: &G, starts: impl IntoIterator<Item = G::Node>) -> Search<G::Node> {
+  best_first(graph, starts, |_| false, |_| 0)
+}
+
+/// A* search from `start`, stopping at the first node where `is_goal` is true. `heuristic` estimates
+/// the remaining cost to a goal and must never overestimate it (`|_| 0` makes this Dijkstra with an
+/// early exit). If it's also consistent (it never drops by more than a step costs), no node is
+/// searched twice; if not, a node reached a shorter way after it was searched is searched again.
+pub fn astar<G: Graph>(
+  graph: &G,
+  start: G::Node,
+  is_goal: impl FnMut(&G::Node) -> bool,
+  heuristic: impl FnMut(&G::Node) -> u64
+) -> Search<G::Node> {
+  astar_multi(graph, [start], is_goal, heuristic)
+}
+
+/// A* search from several starts at once, stopping at the first goal reached from any of them.
+pub fn astar_multi<G: Graph>(
+  graph: &G,
+  starts: impl IntoIterator<Item = G::Node>,
+  is_goal: impl FnMut(&G::Node) -> bool,
+  heuristic: impl FnMut(&G::Node) -> u64
+) -> Search<G::Node> {
+  best_first(graph, starts, is_goal, heuristic)
+}
+
+// Dijkstra and A* both pop the node with the lowest distance + heuristic. Nodes are only recorded in the
+// result once popped, when their distance is final for a consistent heuristic. An inconsistent one can
+// pop a node before its shortest path is found, so finding a shorter one reopens it. The heap holds
+// indexes into `nodes` so that nodes don't need to be `Ord`.
+fn best_first<G: Graph>(
+  graph: &G,
+  starts: impl IntoIterator<Item = G::Node>,
+  mut is_goal: impl FnMut(&G::Node) -> bool,
+  mut heuristic: impl FnMut(&G::Node) -> u64
+) -> Search<G::Node> {
+  let mut search = Search::new();
+  let mut tentative: HashMap<G::Node, (u64, Option<G::Node>)> = HashMap::new();
+  let mut nodes = vec![];
+  let mut heap = BinaryHeap::new();
+
+  for start in starts {
+    heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+    tentative.insert(start.clone(), (0, None));
+    nodes.push(start);
+  }
+
+  while let Some(Reverse((_, distance, i))) = heap.pop() {
+    let curr = nodes[i].clone();
+    if search.distances.get(&curr).is_some_and(|&searched| searched <= distance) || tentative[&curr].0 < distance {
+      continue; // Already reached a better way.
+    }
+    search.distances.insert(curr.clone(), distance);
+    if let Some(parent) = tentative[&curr].1.clone() {
+      search.parents.insert(curr.clone(), parent);
+    }
+    if is_goal(&curr) {
+      search.goal = Some(curr);
+      break;
+    }
+
+    for next in graph.neighbors(&curr) {
+      let next_distance = distance + graph.cost(&curr, &next);
+      if tentative.get(&next).is_none_or(|&(best, _)| next_distance < best) {
+        tentative.insert(next.clone(), (next_distance, Some(curr.clone())));
+        heap.push(Reverse((next_distance + heuristic(&next), next_distance, nodes.len())));
+        nodes.push(next);
+      }
+    }
+  }
+  search
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // A weighted graph where the direct edge isn't the shortest path:
+  //   a --1-- b --1-- c
+  //    \-------5-----/      and d on its own.
+  struct Weighted;
+
+  impl Graph for Weighted {
+    type Node = char;
+
+    fn neighbors(&self, node: &char) -> Vec<char> {
+      match node {
+        'a' => vec!['b', 'c'],
+        'b' => vec!['a', 'c'],
+        'c' => vec!['a', 'b'],
+        _ => vec![]
+      }
+    }
+
+    fn cost(&self, from: &char, to: &char) -> u64 {
+      if [*from, *to] == ['a', 'c'] || [*from, *to] == ['c', 'a'] { 5 } else { 1 }
+    }
+  }
+
+  // An unbounded number line, for checking that A* stops.
+  struct Line;
+
+  impl Graph for Line {
+    type Node = i64;
+
+    fn neighbors(&self, node: &i64) -> Vec<i64> {
+      vec![node - 1, node + 1]
+    }
+  }
+
+  // The heuristic never overestimates, but it drops by 5 going from b to c, which only costs 1, so A*
+  // searches c by way of a (total 4) before it finds the way through b (total 2):
+  //   s --1-- a --3-- c --5-- g
+  //    \--1-- b --1--/
+  struct Inconsistent;
+
+  impl Graph for Inconsistent {
+    type Node = char;
+
+    fn neighbors(&self, node: &char) -> Vec<char> {
+      match node {
+        's' => vec!['a', 'b'],
+        'a' | 'b' => vec!['c'],
+        'c' => vec!['g'],
+        _ => vec![]
+      }
+    }
+
+    fn cost(&self, from: &char, to: &char) -> u64 {
+      match (from, to) {
+        ('a', 'c') => 3,
+        ('c', 'g') => 5,
+        _ => 1
+      }
+    }
+  }
+
+  #[test]
+  fn bfs_counts_steps() {
+    let search = bfs(&Weighted, 'a');
+    assert_eq!((search.distance(&'b'), search.distance(&'c'), search.distance(&'d')), (Some(1), Some(1), None));
+    assert_eq!(search.path(&'c'), Some(vec!['a', 'c']));
+    assert_eq!(search.path(&'d'), None);
+  }
+
+  #[test]
+  fn dijkstra_uses_costs() {
+    let search = dijkstra(&Weighted, 'a');
+    assert_eq!(search.distance(&'c'), Some(2));
+    assert_eq!(search.path(&'c'), Some(vec!['a', 'b', 'c']));
+    assert_eq!(search.distances().len(), 3);
+  }
+
+  #[test]
+  fn adjacency_lists_are_graphs() {
+    let tunnels = HashMap::from([("AA", vec!["BB", "CC"]), ("BB", vec!["AA"]), ("CC", vec!["AA", "DD"]), ("DD", vec!["CC"])]);
+    let search = bfs(&tunnels, "AA");
+    assert_eq!(search.distance(&"DD"), Some(2));
+    assert_eq!(search.path(&"DD"), Some(vec!["AA", "CC", "DD"]));
+  }
+
+  #[test]
+  fn multi_source_uses_the_nearest_start() {
+    let search = bfs_multi(&Line, []);
+    assert!(search.distances().is_empty());
+
+    let tunnels = HashMap::from([(0, vec![1]), (1, vec![2]), (2, vec![3]), (3, vec![]), (10, vec![3])]);
+    let search = bfs_multi(&tunnels, [0, 10]);
+    assert_eq!(search.distance(&3), Some(1));
+    assert_eq!(search.path(&3), Some(vec![10, 3]));
+
+    let search = dijkstra_multi(&Weighted, ['a', 'c']);
+    assert_eq!((search.distance(&'b'), search.distance(&'c')), (Some(1), Some(0)));
+  }
+
+  #[test]
+  fn astar_stops_at_the_goal() {
+    let search = astar(&Line, 0, |&n| n == 7, |&n| (7 - n).unsigned_abs());
+    assert_eq!(search.goal(), Some(&7));
+    assert_eq!(search.distance(&7), Some(7));
+    assert_eq!(search.path(&7), Some((0..=7).collect()));
+    // With a perfect heuristic, nothing behind the start is explored.
+    assert_eq!(search.distance(&-1), None);
+
+    let search = astar_multi(&Line, [-20, 10], |&n| n == 7, |_| 0);
+    assert_eq!((search.goal(), search.distance(&7)), (Some(&7), Some(3)));
+
+    let search = astar(&Weighted, 'a', |&n| n == 'd', |_| 0);
+    assert_eq!(search.goal(), None);
+  }
+
+  #[test]
+  fn astar_reopens_for_inconsistent_heuristics() {
+    let search = astar(&Inconsistent, 's', |&n| n == 'g', |&n| if n == 'b' { 5 } else { 0 });
+    assert_eq!(search.distance(&'g'), Some(7));
+    assert_eq!(search.path(&'g'), Some(vec!['s', 'b', 'c', 'g']));
+    assert_eq!(search.distance(&'c'), Some(2));
+  }
+}
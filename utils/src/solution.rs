@@ -0,0 +1,166 @@
+use std::fmt::Display;
+use crate::error::InputError;
+use crate::input::Input;
+
+/// Which half of a day's puzzle to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+  One,
+  Two
+}
+
+impl Part {
+  pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+  pub fn number(&self) -> u8 {
+    match self {
+      Part::One => 1,
+      Part::Two => 2
+    }
+  }
+
+  pub fn from_number(n: u8) -> Option<Part> {
+    match n {
+      1 => Some(Part::One),
+      2 => Some(Part::Two),
+      _ => None
+    }
+  }
+}
+
+impl Display for Part {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.number())
+  }
+}
+
+/// A day's puzzle: parse the input once, then answer each part from the parsed input.
+/// Each `dayNN` crate implements this on a unit struct (e.g. `day16::Day16`) so the `aoc`
+/// runner can dispatch to it.
+pub trait Solution {
+  const DAY: u8;
+
+  type Input;
+  type Answer1: Display;
+  type Answer2: Display;
+
+  fn parse(input: &Input) -> Result<Self::Input, InputError>;
+  fn part1(input: &Self::Input) -> Self::Answer1;
+  fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// The answer to one part of a day, formatted for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartAnswer {
+  pub part: Part,
+  pub answer: String
+}
+
+impl Display for PartAnswer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    // Multi-line answers (e.g. day 10's CRT) read better starting on their own line.
+    let sep = if self.answer.contains('\n') { "\n" } else { " " };
+    write!(f, "part {}:{sep}{}", self.part, self.answer)
+  }
+}
+
+/// A type-erased `solve::<S>`, so solutions for every day fit in one table.
+pub type Solver = fn(&Input, &[Part], &mut dyn FnMut(PartAnswer)) -> Result<(), InputError>;
+
+/// Parse `input` once and solve each of the requested `parts`, reporting each answer as soon as it's ready.
+pub fn solve<S: Solution>(input: &Input, parts: &[Part], report: &mut dyn FnMut(PartAnswer)) -> Result<(), InputError> {
+  // Spans label each solver's events with the day and part they came from.
+  let _day = tracing::info_span!("day", day = S::DAY).entered();
+  let parsed = tracing::info_span!("parse").in_scope(|| S::parse(input)).map_err(|err| input.locate(err))?;
+  for &part in parts {
+    let _part = tracing::info_span!("part", part = part.number()).entered();
+    let answer = match part {
+      Part::One => S::part1(&parsed).to_string(),
+      Part::Two => S::part2(&parsed).to_string()
+    };
+    report(PartAnswer { part, answer });
+  }
+  Ok(())
+}
+
+/// The `main` for a single day's binary: read the file provided as the first argument (or stdin, for `-`)
+/// and print both answers. Logging is filtered by `AOC_LOG` (see [`crate::trace::init`]).
+pub fn run_from_args<S: Solution>() {
+  if let Err(err) = crate::trace::init(None) {
+    eprintln!("{err}");
+    std::process::exit(2);
+  }
+  let path = crate::args_iter().next().expect("Missing argument");
+  let result = Input::load(&path).and_then(
+    |input| solve::<S>(&input, &Part::BOTH, &mut |answer| println!("Day {} {}", S::DAY, answer))
+  );
+
+  if let Err(err) = result {
+    eprintln!("{err}");
+    std::process::exit(1);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Sum;
+
+  impl Solution for Sum {
+    const DAY: u8 = 0;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+      crate::load_comma_separated_ints(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+      input.iter().sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+      format!("{} numbers", input.len())
+    }
+  }
+
+  fn collect(input: &str, parts: &[Part]) -> Vec<PartAnswer> {
+    let mut answers = vec![];
+    solve::<Sum>(&Input::from(input), parts, &mut |answer| answers.push(answer)).unwrap();
+    answers
+  }
+
+  #[test]
+  fn solve_requested_parts() {
+    assert_eq!(collect("1,2,3\n", &Part::BOTH), vec![
+      PartAnswer { part: Part::One, answer: "6".to_string() },
+      PartAnswer { part: Part::Two, answer: "3 numbers".to_string() }
+    ]);
+    assert_eq!(collect("1,2,3", &[Part::Two]), vec![PartAnswer { part: Part::Two, answer: "3 numbers".to_string() }]);
+  }
+
+  #[test]
+  fn parse_errors_stop_solving() {
+    let mut answers = vec![];
+    let err = solve::<Sum>(&Input::from("1,x"), &Part::BOTH, &mut |answer| answers.push(answer)).unwrap_err();
+    assert_eq!(err.column, Some(3));
+    assert!(answers.is_empty());
+  }
+
+  #[test]
+  fn display_answers() {
+    assert_eq!(PartAnswer { part: Part::One, answer: "6".to_string() }.to_string(), "part 1: 6");
+    assert_eq!(PartAnswer { part: Part::Two, answer: "#.\n.#".to_string() }.to_string(), "part 2:\n#.\n.#");
+  }
+
+  #[test]
+  fn part_numbers() {
+    assert_eq!(Part::from_number(1), Some(Part::One));
+    assert_eq!(Part::from_number(2), Some(Part::Two));
+    assert_eq!(Part::from_number(3), None);
+    assert_eq!(Part::Two.to_string(), "2");
+  }
+}
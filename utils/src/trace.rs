@@ -0,0 +1,42 @@
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+/// The environment variable with the default filter, e.g. `AOC_LOG=day11=debug`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// Print `tracing` events to stderr (so answers on stdout stay clean), keeping those that pass
+/// `filter`, or `AOC_LOG` if there's no filter. With neither, only warnings and errors are shown.
+///
+/// Filters are [`EnvFilter`] directives, where each day logs under its crate name:
+/// `debug`, `day23=trace` or `day11=debug,day24=info`. The solvers log at these levels:
+/// * `info`: a summary of each part, like day 24's time for each leg of the trip
+/// * `debug`: state once per round or phase, like day 11's monkeys after each round
+/// * `trace`: every step, like day 23's elf positions or day 24's whole path
+pub fn init(filter: Option<&str>) -> Result<(), String> {
+  init_with(filter, None)
+}
+
+/// [`init`], plus another layer that sees every event regardless of `filter` (it can have its own),
+/// like the runner's JSON output collecting diagnostics.
+pub fn init_with(filter: Option<&str>, layer: Option<Box<dyn Layer<Registry> + Send + Sync>>) -> Result<(), String> {
+  let filter = match filter.map(str::to_string).or_else(|| std::env::var(ENV_VAR).ok()) {
+    Some(directives) => EnvFilter::try_new(&directives).map_err(|e| format!["Invalid log filter \"{directives}\": {e}"])?,
+    None => EnvFilter::new("warn")
+  };
+  let log = tracing_subscriber::fmt::layer().with_writer(std::io::stderr).without_time().with_filter(filter);
+  // Ignore the error from a second `init`, e.g. in tests, and keep the first subscriber.
+  let _ = tracing_subscriber::registry().with(layer).with(log).try_init();
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rejects_bad_filters() {
+    assert!(init(Some("day11=loud")).is_err());
+    assert!(init(Some("day11=debug,day24=info")).is_ok());
+  }
+}
@@ -0,0 +1,115 @@
+use crate::error::InputError;
+use crate::input::Input;
+use crate::solution::Solution;
+
+/// Something a solver takes for granted about its input without the puzzle promising it, like day
+/// 24's "no blizzards blow up or down the entry and exit columns". An input that breaks one might
+/// make the solver panic, or worse, quietly give a wrong answer.
+#[derive(Debug, Clone, Copy)]
+pub struct Assumption {
+  /// What's assumed, e.g. "Valve IDs are two capital letters".
+  pub description: &'static str,
+  /// Whether the input holds to it, and if not, where it doesn't. It gets the raw input, since some
+  /// inputs that break an assumption can't be parsed (or would panic while parsing). Lines it can't
+  /// make sense of are for `parse` to report, not the check.
+  pub check: fn(&Input) -> Result<(), String>
+}
+
+/// A day that declares what it assumes about its input.
+pub trait Validate: Solution {
+  const ASSUMPTIONS: &'static [Assumption];
+}
+
+/// An assumption an input breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+  pub assumption: &'static str,
+  pub problem: String
+}
+
+impl std::fmt::Display for Violation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {}", self.assumption, self.problem)
+  }
+}
+
+/// A type-erased `violations::<V>`, so validators for every day fit in one table.
+pub type Validator = fn(&Input) -> Vec<Violation>;
+
+/// Every assumption of `V`'s that `input` breaks, without parsing it.
+pub fn violations<V: Validate>(input: &Input) -> Vec<Violation> {
+  V::ASSUMPTIONS.iter()
+    .filter_map(|assumption| (assumption.check)(input).err().map(|problem| Violation { assumption: assumption.description, problem }))
+    .collect()
+}
+
+/// Every assumption of `V`'s that `input` breaks. If it breaks none, it's parsed too, so that a parse
+/// error comes back as the error. (If it breaks some, it isn't, since parsing might be what panics.)
+pub fn validate<V: Validate>(input: &Input) -> Result<Vec<Violation>, InputError> {
+  let violations = violations::<V>(input);
+  if violations.is_empty() {
+    V::parse(input).map_err(|err| input.locate(err))?;
+  }
+  Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Pairs;
+
+  impl Solution for Pairs {
+    const DAY: u8 = 0;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &Input) -> Result<Self::Input, InputError> {
+      crate::load_comma_separated_ints(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+      input.chunks(2).map(|pair| pair[0] * pair[1]).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+      input.iter().sum()
+    }
+  }
+
+  impl Validate for Pairs {
+    const ASSUMPTIONS: &'static [Assumption] = &[
+      Assumption {
+        description: "There's an even number of numbers",
+        check: |input| match input.text().split(',').count() {
+          n if n % 2 == 1 => Err(format!["There are {n}"]),
+          _ => Ok(())
+        }
+      },
+      Assumption {
+        description: "There's one line",
+        check: |input| if input.lines().count() == 1 { Ok(()) } else { Err("There's more than one".to_string()) }
+      }
+    ];
+  }
+
+  #[test]
+  fn report_violations() {
+    assert_eq!(validate::<Pairs>(&Input::from("1,2,3,4")), Ok(vec![]));
+    assert_eq!(validate::<Pairs>(&Input::from("1,2,3")), Ok(vec![
+      Violation { assumption: "There's an even number of numbers", problem: "There are 3".to_string() }
+    ]));
+    assert_eq!(validate::<Pairs>(&Input::from("1,2,3\n4")).unwrap().len(), 2);
+    assert_eq!(
+      validate::<Pairs>(&Input::from("1,2,3")).unwrap()[0].to_string(),
+      "There's an even number of numbers: There are 3"
+    );
+  }
+
+  #[test]
+  fn parse_errors_after_assumptions() {
+    assert_eq!(validate::<Pairs>(&Input::from("1,x")).unwrap_err().column, Some(3));
+  }
+}
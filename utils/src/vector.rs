@@ -0,0 +1,368 @@
+use std::fmt::{Debug, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use num::{Signed, Zero};
+use crate::coordinates::Coord;
+
+/// An integer (or any signed number) vector with `N` components, e.g. a position or a step.
+///
+/// Vectors add, subtract and negate component by component, and multiply, divide or take the
+/// remainder by a scalar. 2D and 3D vectors also have `x()`, `y()` (and `z()`) accessors, and
+/// convert to and from tuples, so `Vector::from((1, 2))` works and a 2D vector compares equal to a
+/// tuple in tests.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+pub type Vector2<T> = Vector<T, 2>;
+pub type Vector3<T> = Vector<T, 3>;
+
+impl<T, const N: usize> Vector<T, N> {
+  pub const fn new(components: [T; N]) -> Self {
+    Vector(components)
+  }
+
+  /// Apply `f` to every component, e.g. `v.map(|c| c as usize)` to use it as an index.
+  pub fn map<U>(self, f: impl FnMut(T) -> U) -> Vector<U, N> {
+    Vector(self.0.map(f))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &T> {
+    self.0.iter()
+  }
+}
+
+impl<T: Copy + Zero, const N: usize> Vector<T, N> {
+  pub fn zero() -> Self {
+    Vector([T::zero(); N])
+  }
+}
+
+impl<T: Copy + Signed, const N: usize> Vector<T, N> {
+  /// The length of the shortest path to `other` in orthogonal steps: the sum of the absolute differences.
+  pub fn manhattan(&self, other: &Self) -> T {
+    (*self - *other).0.iter().fold(T::zero(), |sum, c| sum + c.abs())
+  }
+
+  /// The -1, 0 or 1 of each component, e.g. the single step (diagonals allowed) from one vector toward another.
+  pub fn signum(&self) -> Self {
+    self.map(|c| c.signum())
+  }
+
+  /// One step along each axis in each direction: +x, -x, +y, -y and so on.
+  pub fn orthogonal_units() -> Vec<Self> {
+    let mut units = vec![];
+    for axis in 0..N {
+      for sign in [T::one(), -T::one()] {
+        let mut unit = Self::zero();
+        unit[axis] = sign;
+        units.push(unit);
+      }
+    }
+    units
+  }
+
+  /// Every step of -1, 0 or 1 along each axis except standing still (8 in 2D, 26 in 3D), in
+  /// lexicographic order.
+  pub fn all_units() -> Vec<Self> {
+    let mut units = vec![Self::zero()];
+    for axis in 0..N {
+      units = units.into_iter().flat_map(|unit| [-T::one(), T::zero(), T::one()].map(|c| {
+        let mut next = unit;
+        next[axis] = c;
+        next
+      })).collect();
+    }
+    units.retain(|unit| !unit.is_zero());
+    units
+  }
+
+  /// The 2N vectors one orthogonal step away.
+  pub fn orthogonal_neighbors(&self) -> Vec<Self> {
+    Self::orthogonal_units().into_iter().map(|unit| *self + unit).collect()
+  }
+
+  /// The vectors one step away along more than one axis at once (4 in 2D, 20 in 3D).
+  pub fn diagonal_neighbors(&self) -> Vec<Self> {
+    Self::all_units().into_iter().filter(|unit| unit.iter().filter(|c| !c.is_zero()).count() > 1).map(|unit| *self + unit).collect()
+  }
+
+  /// Every vector one step away, orthogonally or diagonally.
+  pub fn neighbors(&self) -> Vec<Self> {
+    Self::all_units().into_iter().map(|unit| *self + unit).collect()
+  }
+
+  fn is_zero(&self) -> bool {
+    self.0.iter().all(Zero::is_zero)
+  }
+}
+
+impl<T: Copy + Signed + Ord, const N: usize> Vector<T, N> {
+  /// The number of steps to `other` when diagonal steps are allowed: the largest absolute difference.
+  pub fn chebyshev(&self, other: &Self) -> T {
+    (*self - *other).0.iter().map(|c| c.abs()).max().unwrap_or_else(T::zero)
+  }
+}
+
+impl<T: Copy> Vector<T, 2> {
+  pub fn x(&self) -> T {
+    self.0[0]
+  }
+
+  pub fn y(&self) -> T {
+    self.0[1]
+  }
+}
+
+impl<T: Copy + Neg<Output = T>> Vector<T, 2> {
+  /// Rotate 90° counterclockwise when y points up, which is clockwise on a screen where y points down.
+  pub fn rotate_ccw(&self) -> Self {
+    Vector([-self.y(), self.x()])
+  }
+
+  /// Rotate 90° clockwise when y points up, which is counterclockwise on a screen where y points down.
+  pub fn rotate_cw(&self) -> Self {
+    Vector([self.y(), -self.x()])
+  }
+}
+
+impl<T: Copy> Vector<T, 3> {
+  pub fn x(&self) -> T {
+    self.0[0]
+  }
+
+  pub fn y(&self) -> T {
+    self.0[1]
+  }
+
+  pub fn z(&self) -> T {
+    self.0[2]
+  }
+}
+
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+  type Output = T;
+
+  fn index(&self, axis: usize) -> &T {
+    &self.0[axis]
+  }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+  fn index_mut(&mut self, axis: usize) -> &mut T {
+    &mut self.0[axis]
+  }
+}
+
+// Component by component, e.g. `a + b`.
+macro_rules! impl_vector_op {
+  ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+    impl<T: Copy + $op<Output = T>, const N: usize> $op for Vector<T, N> {
+      type Output = Self;
+
+      fn $fn(self, rhs: Self) -> Self {
+        let mut result = self;
+        for i in 0..N {
+          result.0[i] = self.0[i].$fn(rhs.0[i]);
+        }
+        result
+      }
+    }
+
+    impl<T: Copy + $op<Output = T>, const N: usize> $op_assign for Vector<T, N> {
+      fn $fn_assign(&mut self, rhs: Self) {
+        *self = self.$fn(rhs);
+      }
+    }
+  };
+}
+
+// By a scalar, e.g. `v * 3`.
+macro_rules! impl_scalar_op {
+  ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+    impl<T: Copy + $op<Output = T>, const N: usize> $op<T> for Vector<T, N> {
+      type Output = Self;
+
+      fn $fn(self, rhs: T) -> Self {
+        self.map(|c| c.$fn(rhs))
+      }
+    }
+
+    impl<T: Copy + $op<Output = T>, const N: usize> $op_assign<T> for Vector<T, N> {
+      fn $fn_assign(&mut self, rhs: T) {
+        *self = self.$fn(rhs);
+      }
+    }
+  };
+}
+
+impl_vector_op!(Add, add, AddAssign, add_assign);
+impl_vector_op!(Sub, sub, SubAssign, sub_assign);
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign);
+impl_scalar_op!(Div, div, DivAssign, div_assign);
+
+impl<T: Copy + Rem<Output = T>, const N: usize> Rem<T> for Vector<T, N> {
+  type Output = Self;
+
+  fn rem(self, rhs: T) -> Self {
+    self.map(|c| c % rhs)
+  }
+}
+
+impl<T: Neg<Output = T>, const N: usize> Neg for Vector<T, N> {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    self.map(|c| -c)
+  }
+}
+
+impl<T: Copy + Zero + Add<Output = T>, const N: usize> Sum for Vector<T, N> {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.fold(Vector([T::zero(); N]), |sum, v| sum + v)
+  }
+}
+
+impl<T: Copy + Zero, const N: usize> Default for Vector<T, N> {
+  fn default() -> Self {
+    Self::zero()
+  }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+  fn from(components: [T; N]) -> Self {
+    Vector(components)
+  }
+}
+
+impl<T> From<(T, T)> for Vector<T, 2> {
+  fn from((x, y): (T, T)) -> Self {
+    Vector([x, y])
+  }
+}
+
+impl<T> From<(T, T, T)> for Vector<T, 3> {
+  fn from((x, y, z): (T, T, T)) -> Self {
+    Vector([x, y, z])
+  }
+}
+
+impl<T> From<Vector<T, 2>> for (T, T) {
+  fn from(Vector([x, y]): Vector<T, 2>) -> Self {
+    (x, y)
+  }
+}
+
+impl<T> From<Vector<T, 3>> for (T, T, T) {
+  fn from(Vector([x, y, z]): Vector<T, 3>) -> Self {
+    (x, y, z)
+  }
+}
+
+impl From<Coord> for Vector<i32, 3> {
+  fn from(Coord { x, y, z }: Coord) -> Self {
+    Vector([x, y, z])
+  }
+}
+
+impl From<Vector<i32, 3>> for Coord {
+  fn from(Vector([x, y, z]): Vector<i32, 3>) -> Self {
+    Coord { x, y, z }
+  }
+}
+
+impl<T: PartialEq> PartialEq<(T, T)> for Vector<T, 2> {
+  fn eq(&self, (x, y): &(T, T)) -> bool {
+    self.0[0] == *x && self.0[1] == *y
+  }
+}
+
+/// Like a tuple: `(1, -2)`.
+impl<T: Debug, const N: usize> Debug for Vector<T, N> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut tuple = f.debug_tuple("");
+    for c in &self.0 {
+      tuple.field(c);
+    }
+    tuple.finish()
+  }
+}
+
+/// Like a tuple: `(1, -2)`.
+impl<T: Display, const N: usize> Display for Vector<T, N> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "(")?;
+    for (i, c) in self.0.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{c}")?;
+    }
+    write!(f, ")")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn arithmetic() {
+    let a = Vector::new([1, 2, 3]);
+    let b = Vector::from((4, -5, 6));
+    assert_eq!(a + b, Vector([5, -3, 9]));
+    assert_eq!(a - b, Vector([-3, 7, -3]));
+    assert_eq!(-a * 2, Vector([-2, -4, -6]));
+    assert_eq!(b / 2, Vector([2, -2, 3]));
+    assert_eq!(b % 4, Vector([0, -1, 2]));
+    assert_eq!([a, b, a].into_iter().sum::<Vector3<i32>>(), Vector([6, -1, 12]));
+
+    let mut c = a;
+    c += b;
+    c *= 10;
+    c -= Vector([50, 0, 0]);
+    assert_eq!((c.x(), c.y(), c.z()), (0, -30, 90));
+  }
+
+  #[test]
+  fn distances() {
+    let a = Vector::new([2, 9]);
+    let b = Vector::new([5, 2]);
+    assert_eq!((a.manhattan(&b), a.chebyshev(&b)), (10, 7));
+    assert_eq!((b - a).signum(), Vector([1, -1]));
+    assert_eq!(Vector::<i64, 3>::zero().signum(), Vector::zero());
+  }
+
+  #[test]
+  fn rotation() {
+    let right = Vector::new([1, 0]);
+    assert_eq!(right.rotate_ccw(), (0, 1));
+    assert_eq!(right.rotate_cw(), (0, -1));
+    assert_eq!(right.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw(), right);
+    assert_eq!(right.rotate_ccw().rotate_cw(), right);
+  }
+
+  #[test]
+  fn neighbors() {
+    let origin = Vector2::<i32>::zero();
+    assert_eq!(Vector2::<i32>::orthogonal_units(), vec![Vector([1, 0]), Vector([-1, 0]), Vector([0, 1]), Vector([0, -1])]);
+    assert_eq!(Vector::new([5, 5]).orthogonal_neighbors()[1], (4, 5));
+    assert_eq!(origin.diagonal_neighbors(), vec![Vector([-1, -1]), Vector([-1, 1]), Vector([1, -1]), Vector([1, 1])]);
+    assert_eq!(origin.neighbors().len(), 8);
+    assert_eq!(Vector3::<i8>::zero().neighbors().len(), 26);
+    assert_eq!(Vector3::<i8>::zero().diagonal_neighbors().len(), 20);
+  }
+
+  #[test]
+  fn conversions() {
+    let coord = Coord::new(1, 2, 3);
+    let v = Vector::from(coord);
+    assert_eq!(v, Vector([1, 2, 3]));
+    // `Coord::from` is its own constructor from a tuple.
+    let back: Coord = v.into();
+    assert_eq!(back, coord);
+    assert_eq!(<(i32, i32, i32)>::from(v), (1, 2, 3));
+    assert_eq!(<(i64, i64)>::from(Vector::new([-1i64, 7])), (-1, 7));
+    assert_eq!(v.map(|c| c as usize)[2], 3usize);
+    assert_eq!(format!["{v:?} {v}"], "(1, 2, 3) (1, 2, 3)");
+  }
+}